@@ -8,7 +8,9 @@ FROM_ADDRESS="coreum_fun"  # Your address that will pay for transaction fees
 # Testnet
 cat > coreum_fun_contract_instantiate.json << 'EOF'
 {
-  "validator_address": "testcorevaloper1xjehmty2z5j7mfmpzxe8dgrf506c70n37lggga",
+  "validators": [
+    { "address": "testcorevaloper1xjehmty2z5j7mfmpzxe8dgrf506c70n37lggga", "weight": 1 }
+  ],
   "total_tickets": "500",
   "ticket_price": "200000000",
   "max_tickets_per_user": "5",
@@ -34,7 +36,9 @@ cored tx wasm instantiate $CONTRACT_CODE_ID \
 
 # cat > coreum_fun_contract_instantiate.json << 'EOF'
 # {
-#   "validator_address": "corevaloper14e0slqpzhgsakm6fwnh5sk6mu2dmdc9ghxhuw5",
+#   "validators": [
+#     { "address": "corevaloper14e0slqpzhgsakm6fwnh5sk6mu2dmdc9ghxhuw5", "weight": 1 }
+#   ],
 #   "total_tickets": "500",
 #   "ticket_price": "200000000", 
 #   "max_tickets_per_user": "5",
@@ -1,9 +1,11 @@
 use cosmwasm_std::{
-    entry_point, to_json_binary, Addr, BankMsg, Binary, Coin as CosmosCoin, CosmosMsg, Deps,
-    DepsMut, Empty, Env, MessageInfo, Order, Response, StakingMsg, StdError, StdResult, Uint128,
+    entry_point, to_json_binary, Addr, BankMsg, Binary, Coin as CosmosCoin, CosmosMsg, Decimal,
+    Deps, DepsMut, DistributionMsg, Empty, Env, Event, HexBinary, MessageInfo, Order, Response,
+    StakingMsg, StdError, StdResult, Storage, Uint128,
 };
 use cw2::set_contract_version;
 use cw_ownable::{assert_owner, get_ownership, initialize_owner, Action};
+use cw_storage_plus::Bound;
 use prost::Message;
 use std::str::FromStr;
 
@@ -11,18 +13,38 @@ use std::str::FromStr;
 
 use crate::error::ContractError;
 use crate::msg::{
-    AccumulatedRewardsAtUndelegationResponse, AccumulatedRewardsResponse, BonusRewardsResponse,
-    ClaimInfo, ClaimsResponse, CurrentStateResponse, DelegatedAmountResponse, DraftTvlResponse,
-    ExecuteMsg, InstantiateMsg, MigrateMsg, ParticipantInfo, ParticipantsResponse, QueryMsg,
-    TicketHoldersResponse, TicketsSoldResponse, TotalBurnedResponse, UserTicketsResponse,
-    UserWinChanceResponse, WinnerResponse,
+    AccumulatedRewardsAtUndelegationResponse, AccumulatedRewardsResponse,
+    AllowedTransitionsResponse, BonusContributorInfo, BonusContributorsResponse,
+    BonusRewardsResponse, BonusTokenClaimsResponse, ClaimInfo, ClaimsResponse,
+    CurrentStateResponse, DelegatedAmountResponse, DraftTvlResponse, DrawInfo, DrawParams,
+    DrawProofResponse, DrawsResponse, EarliestDrawTimeResponse, ExecuteMsg, FeesCollectedResponse,
+    InstantiateMsg, MigrateMsg, ParticipantInfo, ParticipantsResponse, PauseStatusResponse,
+    PendingWithdrawalsResponse, QueryMsg, RoundHistoryResponse, RoundParams,
+    SlashingStatusResponse, SolvencyResponse, TicketHoldersResponse, TicketsSoldResponse,
+    TotalBurnedResponse, UserTicketsResponse, UserWinChanceResponse, WinnerInfo, WinnerResponse,
 };
 use crate::state::{
-    all_tickets_burned, calculate_win_chance, decrease_ticket_holder_primary_market, get_draft_tvl,
-    increment_tickets_burned, increment_tickets_sold, initialize_storage,
-    should_close_ticket_sales, update_claim, update_ticket_holder_primary_market, Config,
-    DrawState, ACCUMALTED_REWARDS_AT_UNDELEGATION, CLAIMS, CONFIG, TICKET_DENOM,
-    TICKET_HOLDERS_PRIMARY_MARKET, TOTAL_TICKETS_BURNED, TOTAL_TICKETS_SOLD,
+    all_tickets_burned, bonus_token_claims, bonus_tokens, calculate_win_chance,
+    cancelled_rewards_share, clear_bonus_tokens, credit_bonus_tokens, credit_withdrawn_rewards,
+    derive_draw_seed, draw_delegations, draw_liquid_liabilities, draw_share_of, draw_winners,
+    get_draft_tvl, hash_secret, holder_snapshot, increment_ticket_subunits_burned,
+    increment_tickets_sold, initialize_draw_storage, is_paused, is_principal_delegated,
+    liquid_liabilities, min_tickets_sold, pending_withdrawals_total, prize_payouts, protocol_fee,
+    record_delegation, record_holder_tickets, resolve_draw_id, sales_closed, scale_refund,
+    seal_holder_snapshot, should_close_ticket_sales, split_by_weight, start_next_round,
+    ticket_subunits_left, ticket_unit, total_liabilities, update_claim, update_draw,
+    validator_principal, BonusContribution, Config, DrawConfig, DrawProof, DrawState,
+    EmergencyStateChange, Operation, PauseFlags, PendingWithdrawal, RandomnessCommitment,
+    SalesClose, WeightedValidator, ACCUMALTED_REWARDS_AT_UNDELEGATION, BONUS_CONTRIBUTORS,
+    BONUS_TOKENS, BONUS_TOKEN_CLAIMS, BPS_DENOMINATOR, CLAIMS, COMPOUNDED_REWARDS, CONFIG,
+    DEFAULT_DRAW_TIMEOUT_SECONDS, DEFAULT_UNBONDING_SECONDS, DEFAULT_WITHDRAWAL_TIMELOCK_SECONDS,
+    DRAWS, DRAW_DELEGATIONS, DRAW_PROOF, EMERGENCY_STATE_CHANGE, FEES_PAID, HOLDER_SNAPSHOT,
+    HOLDER_SNAPSHOT_TOTAL, LATEST_DRAW_ID, LATEST_WITHDRAWAL_ID,
+    LEGACY_ACCUMALTED_REWARDS_AT_UNDELEGATION, LEGACY_CLAIMS, LEGACY_CONFIG, LEGACY_TICKET_DENOM,
+    LEGACY_TICKET_HOLDERS, LEGACY_TOTAL_TICKETS_BURNED, LEGACY_TOTAL_TICKETS_SOLD, PAUSE_FLAGS,
+    PENDING_WITHDRAWALS, PRINCIPAL_RECOVERED, PRINCIPAL_SETTLED, PRIZES_PAID,
+    RANDOMNESS_COMMITMENT, ROUND_HISTORY, TICKET_PRECISION, TOTAL_FEES_COLLECTED,
+    TOTAL_TICKETS_BURNED, TOTAL_TICKETS_SOLD, WITHDRAWN_REWARDS,
 };
 
 use coreum_wasm_sdk::types::cosmos::base::v1beta1::Coin;
@@ -35,13 +57,25 @@ use coreum_wasm_sdk::types::cosmos::bank::v1beta1::MsgSend;
 use cosmrs::proto::cosmos::bank::v1beta1::QueryDenomOwnersRequest;
 use cosmrs::proto::cosmos::bank::v1beta1::QueryDenomOwnersResponse;
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmrs::proto::cosmos::staking::v1beta1::QueryParamsRequest as QueryStakingParamsRequest;
+use cosmrs::proto::cosmos::staking::v1beta1::QueryParamsResponse as QueryStakingParamsResponse;
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
+    UnbondingDelegation,
+};
 
 // Version info for migration
 const CONTRACT_NAME: &str = "coreum-fun";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // Constants
-const TICKET_PRECISION: u32 = 6;
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+const MAX_PRIZE_TIERS: usize = 10;
+const MAX_FEE_BPS: u64 = 2_000;
+const EMERGENCY_TIMELOCK_SECONDS: u64 = 3 * 24 * 60 * 60;
+const MAX_SPONSOR_INFO_LENGTH: usize = 256;
+const MAX_BONUS_DENOMS: usize = 10;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -55,44 +89,149 @@ pub fn instantiate(
     // Step 1: Set contract version for migrations
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    // Step 2: Validate input parameters
-    if msg.total_tickets.is_zero() {
+    // Step 2: Save the contract wide config
+    let treasury = validate_fee(deps.as_ref(), msg.fee_bps, msg.treasury.clone())?;
+    let guardian = msg
+        .guardian
+        .as_ref()
+        .map(|guardian| deps.api.addr_validate(guardian))
+        .transpose()?;
+    let config = Config {
+        core_denom: msg.core_denom.clone(),
+        fee_bps: msg.fee_bps,
+        treasury,
+        guardian,
+        min_accumulation_seconds: msg.min_accumulation_seconds,
+        fallback_unbonding_seconds: msg
+            .fallback_unbonding_seconds
+            .unwrap_or(DEFAULT_UNBONDING_SECONDS),
+        withdrawal_timelock_seconds: msg
+            .withdrawal_timelock_seconds
+            .unwrap_or(DEFAULT_WITHDRAWAL_TIMELOCK_SECONDS),
+        withdrawal_threshold: msg.withdrawal_threshold.unwrap_or_default(),
+        fractional_tickets: msg.fractional_tickets,
+        draw_timeout_seconds: msg
+            .draw_timeout_seconds
+            .unwrap_or(DEFAULT_DRAW_TIMEOUT_SECONDS),
+        bonus_denoms: vec![],
+    };
+    CONFIG.save(deps.storage, &config)?;
+    TOTAL_FEES_COLLECTED.save(deps.storage, &Uint128::zero())?;
+
+    // Step 3: Create the first draw and its TICKET smart token
+    let (draw_id, issue_token_msg) = create_draw(deps, &env, DrawParams::from(&msg))?;
+
+    // Step 4: Return success response
+    Ok(Response::new()
+        .add_message(issue_token_msg)
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender.to_string())
+        .add_attribute("draw_id", draw_id.to_string())
+        .add_attribute("ticket_token_symbol", msg.ticket_token_symbol)
+        .add_attribute(
+            "validators",
+            msg.validators
+                .iter()
+                .map(|v| v.address.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+        .add_attribute("total_tickets", msg.total_tickets.to_string())
+        .add_attribute("ticket_price", msg.ticket_price.to_string()))
+}
+
+fn create_draw(
+    deps: DepsMut,
+    env: &Env,
+    params: DrawParams,
+) -> Result<(u64, CosmosMsg), ContractError> {
+    // Step 1: Validate input parameters
+    if params.total_tickets.is_zero() {
         return Err(ContractError::InvalidTicketAmount {});
     }
 
-    if msg.ticket_price.is_zero() {
+    if params.ticket_price.is_zero() {
         return Err(ContractError::InvalidTicketPrice {});
     }
 
-    // Step 3: Validate validator address
-    // deps.api.validator_address(&msg.validator_address)?;
+    validate_sales_deadline(env, params.sales_deadline)?;
+    validate_sales_close(
+        env,
+        params.sales_close.as_ref(),
+        params.min_tickets,
+        params.total_tickets,
+    )?;
+    let rewards_fallback = params
+        .rewards_fallback
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
 
-    // Step 4: Initialize config with default values
-    let config = Config {
-        ticket_symbol: msg.ticket_token_symbol.clone(),
-        core_denom: msg.core_denom.clone(),
-        validator_address: msg.validator_address.clone(),
-        total_tickets: msg.total_tickets,
+    // Step 2: Validate the validator set
+    // deps.api.validator_address(&params.validator_address)?;
+    validate_validators(&params.validators)?;
+    let prize_tiers_bps = validate_prize_tiers(params.prize_tiers_bps)?;
+
+    // Step 3: Construct the denom, every draw needs its own TICKET token
+    let denom = format!(
+        "u{}-{}",
+        params.ticket_token_symbol.to_lowercase(),
+        env.contract.address
+    );
+
+    let denom_taken = DRAWS
+        .range(deps.storage, None, None, Order::Ascending)
+        .any(|item| matches!(item, Ok((_, draw)) if draw.ticket_denom == denom));
+    if denom_taken {
+        return Err(ContractError::TokenAlreadyIssued {});
+    }
+
+    // Step 4: Initialize the draw config with default values, TICKET tokens are only divisible
+    // if fractional tickets are enabled
+    let ticket_precision = if CONFIG.load(deps.storage)?.fractional_tickets {
+        TICKET_PRECISION
+    } else {
+        0
+    };
+    let draw = DrawConfig {
+        ticket_symbol: params.ticket_token_symbol.clone(),
+        ticket_denom: denom,
+        validators: params.validators,
+        total_tickets: params.total_tickets,
         //in ucore
-        ticket_price: msg.ticket_price,
-        max_tickets_per_user: msg.max_tickets_per_user,
+        ticket_price: params.ticket_price,
+        max_tickets_per_user: params.max_tickets_per_user,
         draw_state: DrawState::TicketSalesOpen,
-        winner: None,
+        prize_tiers_bps,
+        winners: vec![],
         undelegation_done_timestamp: None,
         accumulated_rewards: Uint128::zero(),
         bonus_rewards: Uint128::zero(),
+        recurring: params.recurring,
+        round: 1,
+        sales_deadline: params.sales_deadline,
+        rewards_fallback,
+        sales_close: params.sales_close.clone(),
+        min_tickets: params.min_tickets,
+        sold_out_at: None,
+        ticket_precision,
+        sales_duration: params
+            .sales_deadline
+            .map(|deadline| deadline - env.block.time.seconds()),
+        sales_close_offset: params
+            .sales_close
+            .as_ref()
+            .map(|close| close.offset_from(&env.block)),
     };
 
-    // Step 5: Save config and initialize counters
-    CONFIG.save(deps.storage, &config)?;
-    initialize_storage(deps.storage)?;
+    // Step 5: Save the draw and initialize its counters
+    let draw_id = initialize_draw_storage(deps.storage, &draw)?;
 
-    // Step 6: Create the TICKET smart token (first time setup)
+    // Step 6: Create the TICKET smart token
     let issue_token_msg = MsgIssue {
         issuer: env.contract.address.to_string(),
-        symbol: msg.ticket_token_symbol.clone(),
-        subunit: format!("u{}", msg.ticket_token_symbol.to_lowercase()),
-        precision: TICKET_PRECISION,
+        symbol: params.ticket_token_symbol.clone(),
+        subunit: format!("u{}", params.ticket_token_symbol.to_lowercase()),
+        precision: ticket_precision,
         initial_amount: "0".to_string(),
         description: "Draft tickets for Coreum No-Loss Draft on coreum.fun".to_string(),
         //Minting & Burning is enabled
@@ -105,24 +244,215 @@ pub fn instantiate(
         extension_settings: None,
     };
 
-    //Step 7 construct the denom and save it in the contract state
-    let denom = format!(
-        "u{}-{}",
-        msg.ticket_token_symbol.to_lowercase(),
-        env.contract.address
-    );
+    Ok((draw_id, CosmosMsg::Any(issue_token_msg.to_any())))
+}
 
-    TICKET_DENOM.save(deps.storage, &denom)?;
+fn validate_validators(validators: &[WeightedValidator]) -> Result<(), ContractError> {
+    let mut addresses: Vec<&str> = validators.iter().map(|v| v.address.as_str()).collect();
+    addresses.sort_unstable();
+    addresses.dedup();
 
-    // Step 7: Return success response
-    Ok(Response::new()
-        .add_message(CosmosMsg::Any(issue_token_msg.to_any()))
-        .add_attribute("method", "instantiate")
-        .add_attribute("owner", info.sender.to_string())
-        .add_attribute("ticket_token_symbol", msg.ticket_token_symbol)
-        .add_attribute("validator_address", msg.validator_address)
-        .add_attribute("total_tickets", msg.total_tickets.to_string())
-        .add_attribute("ticket_price", msg.ticket_price.to_string()))
+    if validators.is_empty()
+        || addresses.len() != validators.len()
+        || validators.iter().any(|v| v.weight == 0)
+    {
+        return Err(ContractError::InvalidValidatorSet {});
+    }
+    Ok(())
+}
+
+// A time-boxed draw closes in the future and its minimum can be sold
+fn validate_sales_close(
+    env: &Env,
+    sales_close: Option<&SalesClose>,
+    min_tickets: Uint128,
+    total_tickets: Uint128,
+) -> Result<(), ContractError> {
+    if sales_close.is_some_and(|close| close.is_reached(&env.block)) || min_tickets > total_tickets
+    {
+        return Err(ContractError::InvalidSalesClose {});
+    }
+    Ok(())
+}
+
+fn validate_sales_deadline(env: &Env, sales_deadline: Option<u64>) -> Result<(), ContractError> {
+    if sales_deadline.is_some_and(|deadline| deadline <= env.block.time.seconds()) {
+        return Err(ContractError::InvalidSalesDeadline {});
+    }
+    Ok(())
+}
+
+// No tiers means a single winner taking the whole prize
+fn validate_prize_tiers(prize_tiers_bps: Vec<u64>) -> Result<Vec<u64>, ContractError> {
+    if prize_tiers_bps.is_empty() {
+        return Ok(vec![BPS_DENOMINATOR]);
+    }
+
+    let total_bps = prize_tiers_bps
+        .iter()
+        .try_fold(0u64, |total, bps| total.checked_add(*bps));
+    if prize_tiers_bps.len() > MAX_PRIZE_TIERS
+        || prize_tiers_bps.contains(&0)
+        || total_bps != Some(BPS_DENOMINATOR)
+    {
+        return Err(ContractError::InvalidPrizeTiers {
+            max_tiers: MAX_PRIZE_TIERS,
+        });
+    }
+    Ok(prize_tiers_bps)
+}
+
+// The fee is capped by MAX_FEE_BPS and needs a treasury to be sent to
+fn validate_fee(
+    deps: Deps,
+    fee_bps: u64,
+    treasury: Option<String>,
+) -> Result<Option<Addr>, ContractError> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ContractError::FeeTooHigh {
+            max_fee_bps: MAX_FEE_BPS,
+        });
+    }
+
+    let treasury = treasury
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    if fee_bps > 0 && treasury.is_none() {
+        return Err(ContractError::MissingTreasury {});
+    }
+    Ok(treasury)
+}
+
+fn assert_not_paused(storage: &dyn Storage, operation: Operation) -> Result<(), ContractError> {
+    if is_paused(storage, &operation)? {
+        return Err(ContractError::ContractPaused {});
+    }
+    Ok(())
+}
+
+// Conditions of every transition of the lifecycle, see `DrawState::next_states`
+fn ensure_transition(
+    storage: &dyn Storage,
+    env: &Env,
+    draw_id: u64,
+    draw: &DrawConfig,
+    new_state: &DrawState,
+) -> Result<(), ContractError> {
+    if !draw.draw_state.next_states().contains(new_state) {
+        return Err(ContractError::InvalidStateTransition {
+            from: format!("{:?}", draw.draw_state),
+            to: format!("{:?}", new_state),
+        });
+    }
+
+    match new_state {
+        DrawState::TicketsSoldOutAccumulationInProgress => {
+            let time_boxed = sales_closed(draw, &env.block) && min_tickets_sold(storage, draw_id)?;
+            if !should_close_ticket_sales(storage, draw_id)? && !time_boxed {
+                return Err(ContractError::CannotCloseTicketSales {});
+            }
+        }
+        DrawState::WinnerSelectedUndelegationInProcess => {
+            if draw.winners.is_empty() {
+                return Err(ContractError::NoWinnerSelected {});
+            }
+            let earliest_draw_time = earliest_draw_time(storage, draw)?.unwrap_or_default();
+            if env.block.time.seconds() < earliest_draw_time {
+                return Err(ContractError::AccumulationPeriodNotElapsed {
+                    current_timestamp: env.block.time.seconds(),
+                    earliest_draw_time,
+                });
+            }
+        }
+        DrawState::UndelegationCompletedTokensCanBeBurned => {
+            let undelegation_timestamp = draw
+                .undelegation_done_timestamp
+                .ok_or(ContractError::NoUndelegationInProgress {})?;
+            if env.block.time.seconds() < undelegation_timestamp {
+                return Err(ContractError::UndelegationPeriodNotCompleted {
+                    current_timestamp: env.block.time.seconds(),
+                    undelegation_timestamp,
+                });
+            }
+        }
+        DrawState::DrawFinished => {
+            if !all_tickets_burned(storage, draw_id)? {
+                return Err(ContractError::NotAllTicketsBurned {});
+            }
+        }
+        DrawState::Cancelled => {
+            let deadline_missed = draw
+                .sales_deadline
+                .is_some_and(|deadline| env.block.time.seconds() >= deadline)
+                && !should_close_ticket_sales(storage, draw_id)?;
+            let too_few_sold =
+                sales_closed(draw, &env.block) && !min_tickets_sold(storage, draw_id)?;
+            let draw_expired = draw.draw_state == DrawState::TicketsSoldOutAccumulationInProgress
+                && draw_deadline(storage, draw)?
+                    .is_some_and(|deadline| env.block.time.seconds() >= deadline);
+            let emergency = EMERGENCY_STATE_CHANGE
+                .may_load(storage, draw_id)?
+                .is_some_and(|change| {
+                    change.new_state == DrawState::Cancelled
+                        && change.from_state == draw.draw_state
+                        && env.block.time.seconds() >= change.executable_at
+                });
+            if !deadline_missed && !too_few_sold && !draw_expired && !emergency {
+                return Err(ContractError::CannotCancelDraw {});
+            }
+        }
+        DrawState::TicketSalesOpen => {}
+    }
+    Ok(())
+}
+
+// Every state change of the lifecycle goes through the transition table
+fn transition_draw_state(
+    storage: &mut dyn Storage,
+    env: &Env,
+    draw_id: u64,
+    new_state: DrawState,
+) -> Result<DrawConfig, ContractError> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    ensure_transition(storage, env, draw_id, &draw, &new_state)?;
+    if new_state == DrawState::TicketsSoldOutAccumulationInProgress {
+        record_sell_out_block(storage, env, draw_id)?;
+    }
+    Ok(update_draw(storage, draw_id, |mut draw| -> StdResult<_> {
+        if new_state == DrawState::TicketsSoldOutAccumulationInProgress {
+            draw.sold_out_at = Some(env.block.time.seconds());
+        }
+        draw.draw_state = new_state;
+        Ok(draw)
+    })?)
+}
+
+// The block that closes ticket sales seeds a committed draw, it is fixed before the secret is
+// revealed so the owner can't time the reveal to pick the winner. The holders the winners are
+// drawn from are frozen in the same block.
+fn record_sell_out_block(storage: &mut dyn Storage, env: &Env, draw_id: u64) -> StdResult<()> {
+    seal_holder_snapshot(storage, draw_id)?;
+    if let Some(mut commitment) = RANDOMNESS_COMMITMENT.may_load(storage, draw_id)? {
+        commitment.sold_out_height = Some(env.block.height);
+        commitment.sold_out_time_nanos = Some(env.block.time.nanos());
+        RANDOMNESS_COMMITMENT.save(storage, draw_id, &commitment)?;
+    }
+    Ok(())
+}
+
+// Rewards accumulate for the minimum accumulation period after ticket sales close
+fn earliest_draw_time(storage: &dyn Storage, draw: &DrawConfig) -> StdResult<Option<u64>> {
+    let config = CONFIG.load(storage)?;
+    Ok(draw
+        .sold_out_at
+        .map(|sold_out_at| sold_out_at + config.min_accumulation_seconds))
+}
+
+// Past this time a draw without winners can be cancelled by anyone and refunded
+fn draw_deadline(storage: &dyn Storage, draw: &DrawConfig) -> StdResult<Option<u64>> {
+    let config = CONFIG.load(storage)?;
+    Ok(earliest_draw_time(storage, draw)?
+        .map(|earliest_draw_time| earliest_draw_time + config.draw_timeout_seconds))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -133,31 +463,99 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::BuyTicket { number_of_tickets } => {
-            execute_buy_ticket(deps, env, info, number_of_tickets)
+        ExecuteMsg::CreateDraw { params } => execute_create_draw(deps, env, info, params),
+        ExecuteMsg::BuyTicket {
+            draw_id,
+            number_of_tickets,
+        } => execute_buy_ticket(deps, env, info, draw_id, number_of_tickets),
+        ExecuteMsg::CancelDraw { draw_id } => execute_cancel_draw(deps, env, info, draw_id),
+        ExecuteMsg::CloseTicketSales { draw_id } => {
+            execute_close_ticket_sales(deps, env, info, draw_id)
         }
-        ExecuteMsg::SelectWinnerAndUndelegate { winner_address } => {
-            execute_select_winner_and_undelegate(deps, env, info, winner_address)
+        ExecuteMsg::SelectWinnerAndUndelegate {
+            draw_id,
+            winner_address,
+            runner_up_addresses,
+        } => execute_select_winner_and_undelegate(
+            deps,
+            env,
+            info,
+            draw_id,
+            winner_address,
+            runner_up_addresses,
+        ),
+        ExecuteMsg::CommitRandomness {
+            draw_id,
+            commitment,
+        } => execute_commit_randomness(deps, env, info, draw_id, commitment),
+        ExecuteMsg::RevealWinner { draw_id, secret } => {
+            execute_reveal_winner(deps, env, info, draw_id, secret)
+        }
+        ExecuteMsg::SendFundsToWinner { draw_id } => {
+            execute_send_funds_to_winner(deps, env, info, draw_id)
+        }
+        ExecuteMsg::ClaimPrize { draw_id } => execute_claim_prize(deps, env, info, draw_id),
+        ExecuteMsg::ClaimBonusTokens { denom } => {
+            execute_claim_bonus_tokens(deps, env, info, denom)
+        }
+        ExecuteMsg::BurnTickets {
+            draw_id,
+            number_of_tickets,
+            ticket_subunits,
+        } => execute_burn_tickets(deps, env, info, draw_id, number_of_tickets, ticket_subunits),
+        ExecuteMsg::AddBonusRewardToThePool {
+            draw_id,
+            amount,
+            memo,
+            logo_uri,
+        } => execute_add_bonus_reward(deps, env, info, draw_id, amount, memo, logo_uri),
+        ExecuteMsg::UpdateDrawState { draw_id, new_state } => {
+            execute_update_draw_state(deps, env, info, draw_id, new_state)
         }
-        ExecuteMsg::SendFundsToWinner {} => execute_send_funds_to_winner(deps, env, info),
-        ExecuteMsg::BurnTickets { number_of_tickets } => {
-            execute_burn_tickets(deps, env, info, number_of_tickets)
+        ExecuteMsg::ScheduleEmergencyStateChange { draw_id, new_state } => {
+            execute_schedule_emergency_state_change(deps, env, info, draw_id, new_state)
         }
-        ExecuteMsg::AddBonusRewardToThePool { amount } => {
-            execute_add_bonus_reward(deps, env, info, amount)
+        ExecuteMsg::ExecuteEmergencyStateChange { draw_id } => {
+            execute_emergency_state_change(deps, env, info, draw_id)
         }
-        ExecuteMsg::UpdateDrawState { new_state } => {
-            execute_update_draw_state(deps, env, info, new_state)
+        ExecuteMsg::CancelEmergencyStateChange { draw_id } => {
+            execute_cancel_emergency_state_change(deps, env, info, draw_id)
         }
         ExecuteMsg::SendFunds { recipient, amount } => {
             execute_send_funds(deps, env, info, recipient, amount)
         }
-        ExecuteMsg::SetUndelegationTimestamp { timestamp } => {
-            execute_set_undelegation_timestamp(deps, env, info, timestamp)
+        ExecuteMsg::ExecuteWithdrawal { withdrawal_id } => {
+            execute_execute_withdrawal(deps, env, info, withdrawal_id)
+        }
+        ExecuteMsg::CancelWithdrawal { withdrawal_id } => {
+            execute_cancel_withdrawal(deps, env, info, withdrawal_id)
+        }
+        ExecuteMsg::SetUndelegationTimestamp { draw_id, timestamp } => {
+            execute_set_undelegation_timestamp(deps, env, info, draw_id, timestamp)
+        }
+        ExecuteMsg::TransferTokenAdmin { draw_id, new_admin } => {
+            transfer_token_admin(deps, env, info, draw_id, new_admin)
+        }
+        ExecuteMsg::StartNextRound { draw_id, params } => {
+            execute_start_next_round(deps, env, info, draw_id, params)
         }
-        ExecuteMsg::TransferTokenAdmin { new_admin } => {
-            transfer_token_admin(deps, env, info, new_admin)
+        ExecuteMsg::Compound { draw_id } => execute_compound(deps, env, info, draw_id),
+        ExecuteMsg::WithdrawRewards {} => execute_withdraw_rewards(deps, env, info),
+        ExecuteMsg::Redelegate {
+            draw_id,
+            from_validator,
+            to_validator,
+        } => execute_redelegate(deps, env, info, draw_id, from_validator, to_validator),
+        ExecuteMsg::Pause { operations } => execute_pause(deps, env, info, operations),
+        ExecuteMsg::Unpause { operations } => execute_unpause(deps, env, info, operations),
+        ExecuteMsg::SetGuardian { guardian } => execute_set_guardian(deps, env, info, guardian),
+        ExecuteMsg::SetMinAccumulationPeriod { seconds } => {
+            execute_set_min_accumulation_period(deps, env, info, seconds)
         }
+        ExecuteMsg::UpdateFeeConfig { fee_bps, treasury } => {
+            execute_update_fee_config(deps, env, info, fee_bps, treasury)
+        }
+        ExecuteMsg::SetBonusDenoms { denoms } => execute_set_bonus_denoms(deps, env, info, denoms),
         ExecuteMsg::UpdateOwnership(action) => {
             update_ownership(deps.into_empty(), env, info, action)
         }
@@ -177,16 +575,50 @@ fn update_ownership(
         .add_attributes(ownership.into_attributes()))
 }
 
-pub fn execute_buy_ticket(
+pub fn execute_create_draw(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    params: DrawParams,
+) -> Result<Response, ContractError> {
+    // Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+
+    let ticket_token_symbol = params.ticket_token_symbol.clone();
+    let (draw_id, issue_token_msg) = create_draw(deps, &env, params)?;
+
+    Ok(Response::new()
+        .add_message(issue_token_msg)
+        .add_attributes(vec![
+            ("action", "create_draw"),
+            ("draw_id", &draw_id.to_string()),
+            ("ticket_token_symbol", &ticket_token_symbol),
+        ]))
+}
+
+pub fn execute_buy_ticket(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    draw_id: Option<u64>,
     number_of_tickets: Uint128,
 ) -> Result<Response, ContractError> {
     // Step 1: Verify the COREUM amount sent
+    assert_not_paused(deps.storage, Operation::Buy)?;
     let config = CONFIG.load(deps.storage)?;
-    let required_payment = number_of_tickets * config.ticket_price;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let required_payment = number_of_tickets * draw.ticket_price;
 
+    if let Some(coin) = info
+        .funds
+        .iter()
+        .find(|coin| coin.denom != config.core_denom)
+    {
+        return Err(ContractError::UnexpectedDenom {
+            denom: coin.denom.clone(),
+        });
+    }
     let payment = info
         .funds
         .iter()
@@ -199,73 +631,99 @@ pub fn execute_buy_ticket(
             provided: payment.amount,
         });
     }
+    // Anything above the price of the tickets is sent back
+    let refund_amount = payment.amount - required_payment;
 
     // Step 2: Verify that draft is still open for ticket sales
-    if config.draw_state != DrawState::TicketSalesOpen {
+    if draw.draw_state != DrawState::TicketSalesOpen
+        || draw
+            .sales_deadline
+            .is_some_and(|deadline| env.block.time.seconds() >= deadline)
+        || sales_closed(&draw, &env.block)
+    {
         return Err(ContractError::TicketSalesClosed {});
     }
 
     // Step 3: Verify that some tickets are left
-    let total_sold = TOTAL_TICKETS_SOLD.load(deps.storage)?;
-    if total_sold + number_of_tickets > config.total_tickets {
+    let total_sold = TOTAL_TICKETS_SOLD.load(deps.storage, draw_id)?;
+    if total_sold + number_of_tickets > draw.total_tickets {
         return Err(ContractError::NotEnoughTicketsLeft {
             requested: number_of_tickets,
-            available: config.total_tickets - total_sold,
+            available: draw.total_tickets - total_sold,
         });
     }
 
-    // Step 4: Verify the user have less tickets than the max allowed (counting the new purchase)
-    let balance = query_ticket_balance(deps.as_ref(), info.sender.to_string())?;
-    let user_tickets: Uint128 =
-        Uint128::from_str(&balance.balance)? / Uint128::from(10u128).pow(TICKET_PRECISION);
-    let max_tickets_per_user = CONFIG.load(deps.storage)?.max_tickets_per_user;
+    // Step 4: Verify the user have less tickets than the max allowed (counting the new purchase),
+    // a fraction of a ticket counts towards the max
+    let unit = ticket_unit(&draw);
+    let balance = query_ticket_balance(deps.as_ref(), draw_id, info.sender.to_string())?;
+    let user_subunits = Uint128::from_str(&balance.balance)?;
+    let max_subunits = draw.max_tickets_per_user * unit;
 
-    if user_tickets + number_of_tickets > max_tickets_per_user {
+    if user_subunits + number_of_tickets * unit > max_subunits {
         return Err(ContractError::MaxTicketsPerUserReached {
             requested: number_of_tickets,
-            available: max_tickets_per_user.saturating_sub(user_tickets),
+            available: max_subunits.saturating_sub(user_subunits) / unit,
         });
     }
 
-    // Step 5: Stake the COREUM to Coreum Labs validator
+    // Step 5: Stake the COREUM to the validator set, split by weight
     // This is done with every ticket purchase - funds are immediately staked
-    let stake_msg = StakingMsg::Delegate {
-        validator: config.validator_address.clone(),
-        amount: CosmosCoin {
-            denom: config.core_denom.clone(),
-            amount: required_payment,
-        },
-    };
+    let mut stake_msgs: Vec<CosmosMsg> = vec![];
+    for (validator, amount) in split_by_weight(required_payment, &draw.validators) {
+        // Delegating withdraws the pending rewards, credit them before the principal changes
+        withdraw_validator_rewards(deps.branch(), &env, &config.core_denom, &validator)?;
+        record_delegation(deps.storage, draw_id, &validator, amount)?;
+        stake_msgs.push(CosmosMsg::Staking(StakingMsg::Delegate {
+            validator,
+            amount: CosmosCoin {
+                denom: config.core_denom.clone(),
+                amount,
+            },
+        }));
+    }
 
     // Step 6: Mint and send the TICKET smart token to the user
     let mint_msg = MsgMint {
         sender: env.contract.address.to_string(),
         coin: Some(Coin {
-            denom: TICKET_DENOM.load(deps.storage)?,
-            amount: (number_of_tickets * Uint128::from(10u128).pow(TICKET_PRECISION)).to_string(),
+            denom: draw.ticket_denom.clone(),
+            amount: (number_of_tickets * unit).to_string(),
         }),
         recipient: info.sender.to_string(),
     };
 
-    // Step 7: Update the contract internal state
-    increment_tickets_sold(deps.storage, number_of_tickets)?;
-    update_ticket_holder_primary_market(deps.storage, &info.sender, number_of_tickets)?;
+    // Step 7: Update the contract internal state, the tickets enter the holder snapshot of
+    // their buyer
+    increment_tickets_sold(deps.storage, draw_id, number_of_tickets)?;
+    record_holder_tickets(
+        deps.storage,
+        draw_id,
+        &info.sender,
+        number_of_tickets * unit,
+    )?;
 
     // Step 8: Check if this was the last ticket - set draw_state=tickets_sold_out_accumulation_in_progress
+    let draw_id_str = draw_id.to_string();
     let tickets_str = number_of_tickets.to_string();
     let payment_str = required_payment.to_string();
+    let refund_str = refund_amount.to_string();
     let mut attrs = vec![
         ("action", "buy_ticket"),
+        ("draw_id", &draw_id_str),
         ("buyer", info.sender.as_str()),
         ("tickets_purchased", &tickets_str),
         ("payment_amount", &payment_str),
+        ("refund_amount", &refund_str),
     ];
 
-    if should_close_ticket_sales(deps.storage)? {
-        CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-            config.draw_state = DrawState::TicketsSoldOutAccumulationInProgress;
-            Ok(config)
-        })?;
+    if should_close_ticket_sales(deps.storage, draw_id)? {
+        transition_draw_state(
+            deps.storage,
+            &env,
+            draw_id,
+            DrawState::TicketsSoldOutAccumulationInProgress,
+        )?;
         attrs.push(("ticket_sales", "closed"));
         attrs.push(("new_state", "TicketsSoldOutAccumulationInProgress"));
     }
@@ -274,197 +732,905 @@ pub fn execute_buy_ticket(
     // Handled by the attributes in the response
 
     // Step 10: Return response with all messages and events
-    Ok(Response::new()
-        .add_message(CosmosMsg::Staking(stake_msg))
-        .add_message(CosmosMsg::Any(mint_msg.to_any()))
-        .add_attributes(attrs))
+    let mut response = Response::new()
+        .add_messages(stake_msgs)
+        .add_message(CosmosMsg::Any(mint_msg.to_any()));
+    if !refund_amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![CosmosCoin {
+                denom: config.core_denom,
+                amount: refund_amount,
+            }],
+        });
+    }
+    Ok(response.add_attributes(attrs))
 }
 
 pub fn execute_select_winner_and_undelegate(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    draw_id: Option<u64>,
     winner_address: String,
+    runner_up_addresses: Vec<String>,
 ) -> Result<Response, ContractError> {
-    // Step 1: Receive the winner address
-    let winner_addr = deps.api.addr_validate(&winner_address)?;
+    // Step 1: Receive the winner addresses, one per prize tier
+    let winners = std::iter::once(winner_address)
+        .chain(runner_up_addresses)
+        .map(|address| deps.api.addr_validate(&address))
+        .collect::<StdResult<Vec<Addr>>>()?;
 
     // Step 2: Verify the caller is the owner
-    let config = CONFIG.load(deps.storage)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
     assert_owner(deps.storage, &info.sender)?;
 
     // Step 3: Verify the draw is in the correct state
-    if config.draw_state != DrawState::TicketsSoldOutAccumulationInProgress {
+    if draw.draw_state != DrawState::TicketsSoldOutAccumulationInProgress {
         return Err(ContractError::InvalidDrawState {
             expected: DrawState::TicketsSoldOutAccumulationInProgress,
-            actual: config.draw_state,
+            actual: draw.draw_state,
         });
     }
 
-    // Step 4: Verify the winner has tickets
-    let holders = query_ticket_holders(deps.as_ref())?;
-    let winner_tickets = holders
-        .holders
-        .iter()
-        .find(|h| h.address == winner_address)
-        .map(|h| h.tickets)
-        .unwrap_or(Uint128::zero());
-
-    if winner_tickets.is_zero() {
-        return Err(ContractError::NoTicketsForAddress {});
+    // Step 4: Once the owner committed to a secret, the winner can only be drawn by revealing it
+    if RANDOMNESS_COMMITMENT.has(deps.storage, draw_id) {
+        return Err(ContractError::CommitRevealRequired {});
     }
 
-    // Step 5: Query accumulated rewards
-    let accumulated_rewards = query_accumulated_rewards(deps.as_ref(), &env)?;
+    // Step 5: Verify there is one distinct winner per tier (or every holder wins) and that
+    // every winner bought tickets
+    let holders = holder_snapshot(deps.storage, draw_id)?.unwrap_or_default();
+    let expected_winners = draw.prize_tiers_bps.len().min(holders.len());
+    let mut distinct_winners = winners.clone();
+    distinct_winners.sort();
+    distinct_winners.dedup();
+    if winners.len() != expected_winners || distinct_winners.len() != winners.len() {
+        return Err(ContractError::InvalidWinners {
+            expected: expected_winners,
+            provided: winners.len(),
+        });
+    }
 
-    ACCUMALTED_REWARDS_AT_UNDELEGATION
-        .save(deps.storage, &accumulated_rewards.accumulated_rewards)?;
+    for winner in &winners {
+        let winner_tickets = holders
+            .iter()
+            .find(|(address, _)| address == winner)
+            .map(|(_, subunits)| *subunits)
+            .unwrap_or(Uint128::zero());
 
-    let total_rewards = accumulated_rewards.accumulated_rewards + config.bonus_rewards;
+        if winner_tickets.is_zero() {
+            return Err(ContractError::NoTicketsForAddress {});
+        }
+    }
 
-    // // Ensure we have rewards to send
-    // if total_rewards.is_zero() {
-    //     return Err(ContractError::NoRewardsToSend {});
-    // }
+    // Step 6: Set the winners and start the undelegation
+    undelegate_draw(
+        deps,
+        &env,
+        draw_id,
+        draw,
+        winners,
+        DrawState::WinnerSelectedUndelegationInProcess,
+        "select_winner_and_undelegate",
+    )
+}
 
-    // Step 6: Set the winner address in the contract state
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.winner = Some(winner_addr.clone());
-        config.accumulated_rewards = accumulated_rewards.accumulated_rewards;
-        config.draw_state = DrawState::WinnerSelectedUndelegationInProcess;
-        Ok(config)
-    })?;
+pub fn execute_cancel_draw(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    draw_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Verify the sales deadline passed without selling out, anyone can cancel then
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    ensure_transition(deps.storage, &env, draw_id, &draw, &DrawState::Cancelled)?;
 
-    // Step 7: Start the undelegation process for all the tokens
-    let delegation = deps.querier.query_delegation(
-        env.contract.address.to_string(),
-        config.validator_address.clone(),
-    )?;
+    cancel_draw(deps, &env, draw_id, draw, "cancel_draw")
+}
 
-    let mut messages: Vec<CosmosMsg> = vec![];
-    if let Some(delegation) = delegation {
-        let undelegate_msg = StakingMsg::Undelegate {
-            validator: config.validator_address.clone(),
-            amount: CosmosCoin {
-                denom: config.core_denom.clone(),
-                amount: delegation.amount.amount,
-            },
-        };
-        messages.push(CosmosMsg::Staking(undelegate_msg));
+pub fn execute_close_ticket_sales(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    draw_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the sales close of the draw is reached, anyone can close sales then
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    if draw.draw_state != DrawState::TicketSalesOpen || !sales_closed(&draw, &env.block) {
+        return Err(ContractError::CannotCloseTicketSales {});
     }
 
-    // Step 8: Calculate the timestamp at which the undelegation will be completed
-    const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
-    const UNDELEGATION_DAYS: u64 = 7;
-    let undelegation_period_seconds: u64 = SECONDS_PER_DAY * UNDELEGATION_DAYS;
-    let undelegation_done_timestamp = env.block.time.seconds() + undelegation_period_seconds;
+    // Step 2: Below the minimum tickets the draw is cancelled and refunded
+    if !min_tickets_sold(deps.storage, draw_id)? {
+        return cancel_draw(deps, &env, draw_id, draw, "close_ticket_sales");
+    }
 
-    // Step 9: Update the contract state with the future timestamp
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.undelegation_done_timestamp = Some(undelegation_done_timestamp);
-        Ok(config)
-    })?;
+    // Step 3: Otherwise rewards accumulate on the tickets sold, held as of now
+    transition_draw_state(
+        deps.storage,
+        &env,
+        draw_id,
+        DrawState::TicketsSoldOutAccumulationInProgress,
+    )?;
 
-    // Return response with all actions
-    Ok(Response::new().add_messages(messages).add_attributes(vec![
-        ("action", "select_winner_and_undelegate"),
-        ("winner", &winner_addr.to_string()),
-        ("rewards_amount", &total_rewards.to_string()),
+    Ok(Response::new().add_attributes(vec![
+        ("action", "close_ticket_sales"),
+        ("draw_id", &draw_id.to_string()),
         (
-            "undelegation_done_timestamp",
-            &undelegation_done_timestamp.to_string(),
+            "tickets_sold",
+            &TOTAL_TICKETS_SOLD.load(deps.storage, draw_id)?.to_string(),
         ),
-        ("new_state", "WinnerSelectedUndelegationInProcess"),
+        ("new_state", "TicketsSoldOutAccumulationInProgress"),
     ]))
 }
 
-pub fn execute_send_funds_to_winner(
+// Return the bonus rewards of a cancelled draw to their sponsors: the CORE is sent back and the
+// tokens are credited to be claimed. Returns the messages and the CORE sent.
+fn return_bonus_rewards(
+    storage: &mut dyn Storage,
+    config: &Config,
+    draw_id: u64,
+) -> StdResult<(Vec<CosmosMsg>, Uint128)> {
+    let contributions = BONUS_CONTRIBUTORS
+        .prefix(draw_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut messages = vec![];
+    let mut returned = Uint128::zero();
+    for (sponsor, contribution) in contributions {
+        credit_bonus_tokens(storage, &sponsor, &contribution.tokens)?;
+        if !contribution.amount.is_zero() {
+            returned += contribution.amount;
+            messages.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: sponsor.to_string(),
+                amount: vec![CosmosCoin {
+                    denom: config.core_denom.clone(),
+                    amount: contribution.amount,
+                }],
+            }));
+        }
+    }
+    clear_bonus_tokens(storage, draw_id)?;
+    update_draw(storage, draw_id, |mut draw| -> StdResult<_> {
+        draw.bonus_rewards = draw.bonus_rewards.saturating_sub(returned);
+        Ok(draw)
+    })?;
+    Ok((messages, returned))
+}
+
+fn cancel_draw(
+    mut deps: DepsMut,
+    env: &Env,
+    draw_id: u64,
+    draw: DrawConfig,
+    action: &str,
+) -> Result<Response, ContractError> {
+    let rewards_fallback = draw.rewards_fallback.clone();
+    let config = CONFIG.load(deps.storage)?;
+
+    // Sending the rewards to the fallback recipient and the bonus back to their sponsors are
+    // payouts, they wait while payouts are paused
+    if rewards_fallback.is_some() || !draw.bonus_rewards.is_zero() {
+        assert_not_paused(deps.storage, Operation::Payout)?;
+    }
+
+    // The undelegation withdraws the pending rewards of the draw validators, they only reach
+    // the contract balance once the messages are executed
+    let mut withdrawn = Uint128::zero();
+    for (validator, _) in draw_delegations(deps.storage, draw_id)? {
+        withdrawn += query_validator_rewards(deps.as_ref(), env, &config.core_denom, &validator)?;
+    }
+
+    // Step 1: Undelegate the whole principal, there is no winner
+    let mut response = undelegate_draw(
+        deps.branch(),
+        env,
+        draw_id,
+        draw,
+        vec![],
+        DrawState::Cancelled,
+        action,
+    )?;
+
+    // Without tickets sold there is nothing to refund
+    if all_tickets_burned(deps.storage, draw_id)? {
+        transition_draw_state(deps.storage, env, draw_id, DrawState::DrawFinished)?;
+        response = response.add_attribute("new_state", "DrawFinished");
+    }
+
+    // Step 2: The bonus rewards go back to their sponsors, in CORE and in tokens
+    let (messages, mut outflow) = return_bonus_rewards(deps.storage, &config, draw_id)?;
+    response = response.add_messages(messages);
+
+    // Step 3: Send the staking rewards to the fallback recipient, otherwise they are refunded
+    // pro-rata with the tickets. The undelegation withdraws the pending rewards first.
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let rewards = draw.accumulated_rewards + draw.bonus_rewards;
+    if let Some(recipient) = rewards_fallback.filter(|_| !rewards.is_zero()) {
+        outflow += rewards;
+        response = response
+            .add_message(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![CosmosCoin {
+                    denom: config.core_denom.clone(),
+                    amount: rewards,
+                }],
+            })
+            .add_attribute("rewards_fallback", recipient);
+    }
+    if !outflow.is_zero() {
+        ensure_solvent_after(deps.as_ref(), env, &config, withdrawn, outflow)?;
+    }
+    Ok(response)
+}
+
+pub fn execute_commit_randomness(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    draw_id: Option<u64>,
+    commitment: HexBinary,
 ) -> Result<Response, ContractError> {
     // Step 1: Verify the caller is the owner
-    let config = CONFIG.load(deps.storage)?;
-
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
     assert_owner(deps.storage, &info.sender)?;
 
-    // Step 2: Verify the draw is in the correct state
-    if config.draw_state != DrawState::WinnerSelectedUndelegationInProcess {
+    // Step 2: The commitment must be made before the outcome can be known, i.e. during ticket sales
+    if draw.draw_state != DrawState::TicketSalesOpen {
         return Err(ContractError::InvalidDrawState {
-            expected: DrawState::WinnerSelectedUndelegationInProcess,
-            actual: config.draw_state,
+            expected: DrawState::TicketSalesOpen,
+            actual: draw.draw_state,
         });
     }
 
-    // Step 3: Check if undelegation period is complete
-    // if let Some(undelegation_timestamp) = config.undelegation_done_timestamp {
-    //     if env.block.time.seconds() < undelegation_timestamp {
-    //         return Err(ContractError::UndelegationPeriodNotCompleted {
-    //             current_timestamp: env.block.time.seconds(),
-    //             undelegation_timestamp,
-    //         });
-    //     }
-    // }
-
-    // Step 4: Get winner address
-    let winner_addr = config.winner.ok_or(ContractError::NoWinnerSelected {})?;
-
-    // Step 5: Calculate total rewards
-    let total_rewards = config.accumulated_rewards + config.bonus_rewards;
+    // Step 3: A commitment cannot be replaced, otherwise the owner could pick a new secret
+    if RANDOMNESS_COMMITMENT.has(deps.storage, draw_id) {
+        return Err(ContractError::RandomnessAlreadyCommitted {});
+    }
 
-    // Ensure we have rewards to send
-    if total_rewards.is_zero() {
-        return Err(ContractError::NoRewardsToSend {});
+    if commitment.len() != 32 {
+        return Err(ContractError::InvalidRandomnessCommitment {});
     }
 
-    // Step 6: Send the rewards to the winner
-    let send_rewards_msg = CosmosMsg::Any(
-        MsgSend {
-            from_address: env.contract.address.to_string(),
-            to_address: winner_addr.to_string(),
-            amount: vec![Coin {
-                denom: config.core_denom.clone(),
-                amount: total_rewards.to_string(),
-            }],
-        }
-        .to_any(),
-    );
+    // Step 4: Save the commitment
+    RANDOMNESS_COMMITMENT.save(
+        deps.storage,
+        draw_id,
+        &RandomnessCommitment {
+            commitment: commitment.clone(),
+            committed_at_height: env.block.height,
+            sold_out_height: None,
+            sold_out_time_nanos: None,
+        },
+    )?;
 
-    // Return response with all actions
-    Ok(Response::new()
-        .add_message(send_rewards_msg)
-        .add_attributes(vec![
-            ("action", "send_funds_to_winner"),
-            ("winner", &winner_addr.to_string()),
-            ("rewards_amount", &total_rewards.to_string()),
-        ]))
+    Ok(Response::new().add_attributes(vec![
+        ("action", "commit_randomness"),
+        ("draw_id", &draw_id.to_string()),
+        ("commitment", &commitment.to_hex()),
+        ("committed_at_height", &env.block.height.to_string()),
+    ]))
 }
 
-pub fn execute_burn_tickets(
+pub fn execute_reveal_winner(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    number_of_tickets: Uint128,
+    draw_id: Option<u64>,
+    secret: HexBinary,
 ) -> Result<Response, ContractError> {
-    // Step 1: Verify the draw is in the correct state
-    let config = CONFIG.load(deps.storage)?;
-    if config.draw_state != DrawState::WinnerSelectedUndelegationInProcess
-        && config.draw_state != DrawState::UndelegationCompletedTokensCanBeBurned
-    {
+    // Step 1: Verify the caller is the owner
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    assert_owner(deps.storage, &info.sender)?;
+
+    // Step 2: Verify the draw is in the correct state
+    if draw.draw_state != DrawState::TicketsSoldOutAccumulationInProgress {
         return Err(ContractError::InvalidDrawState {
-            expected: DrawState::UndelegationCompletedTokensCanBeBurned,
-            actual: config.draw_state,
+            expected: DrawState::TicketsSoldOutAccumulationInProgress,
+            actual: draw.draw_state,
         });
     }
 
+    // Step 3: Verify the secret matches the commitment
+    let commitment = RANDOMNESS_COMMITMENT
+        .may_load(deps.storage, draw_id)?
+        .ok_or(ContractError::NoRandomnessCommitment {})?;
+
+    if hash_secret(secret.as_slice()) != commitment.commitment {
+        return Err(ContractError::InvalidRandomnessReveal {});
+    }
+
+    // Step 4: Derive the winning tickets of every tier from the secret and the block that
+    // closed ticket sales, among the TICKET subunits sold
+    let (Some(block_height), Some(block_time_nanos)) =
+        (commitment.sold_out_height, commitment.sold_out_time_nanos)
+    else {
+        return Err(ContractError::InvalidDrawState {
+            expected: DrawState::TicketsSoldOutAccumulationInProgress,
+            actual: draw.draw_state,
+        });
+    };
+    let holders = holder_snapshot(deps.storage, draw_id)?.unwrap_or_default();
+    let total_subunits: Uint128 = holders.iter().map(|(_, subunits)| *subunits).sum();
+    let seed = derive_draw_seed(secret.as_slice(), block_height, block_time_nanos);
+    let (ticket_indexes, winners) = draw_winners(
+        deps.storage,
+        draw_id,
+        &seed,
+        total_subunits,
+        draw.prize_tiers_bps.len(),
+    )?;
+    if winners.is_empty() {
+        return Err(ContractError::NoTicketsForAddress {});
+    }
+
+    // Step 5: Store the proof so anyone can re-verify the draw
+    DRAW_PROOF.save(
+        deps.storage,
+        draw_id,
+        &DrawProof {
+            commitment: commitment.commitment,
+            secret,
+            block_height,
+            block_time_nanos,
+            seed: seed.clone(),
+            ticket_indexes: ticket_indexes.clone(),
+            total_tickets: total_subunits,
+            winners: winners.clone(),
+        },
+    )?;
+
+    // Step 6: Set the winners and start the undelegation
+    let response = undelegate_draw(
+        deps,
+        &env,
+        draw_id,
+        draw,
+        winners,
+        DrawState::WinnerSelectedUndelegationInProcess,
+        "reveal_winner",
+    )?;
+
+    Ok(response.add_attributes(vec![
+        ("seed", seed.to_hex()),
+        (
+            "ticket_indexes",
+            ticket_indexes
+                .iter()
+                .map(|index| index.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+    ]))
+}
+
+// Set the winners (none for a cancelled draw), move the draw to `new_state` and undelegate
+// its whole principal
+fn undelegate_draw(
+    mut deps: DepsMut,
+    env: &Env,
+    draw_id: u64,
+    draw: DrawConfig,
+    winners: Vec<Addr>,
+    new_state: DrawState,
+    action: &str,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Step 1: Undelegating withdraws the pending rewards, credit them so the accumulated
+    // rewards are everything withdrawn by the draw
+    for (validator, _) in draw_delegations(deps.storage, draw_id)? {
+        withdraw_validator_rewards(deps.branch(), env, &config.core_denom, &validator)?;
+    }
+
+    // Step 2: Compute the part of each validator delegation that belongs to this draw,
+    // before the draw state change removes its principal from the validator principal
+    let delegations = query_draw_delegations(deps.as_ref(), env, draw_id)?;
+
+    // Record what actually comes back, less than the principal if a validator was slashed.
+    // Slashing hits the compounded rewards first, the principal only once they are gone
+    let undelegated: Uint128 = delegations.iter().map(|(_, amount)| *amount).sum();
+    let principal = get_draft_tvl(deps.storage, draw_id)?;
+    PRINCIPAL_RECOVERED.save(deps.storage, draw_id, &undelegated.min(principal))?;
+
+    let compounded = COMPOUNDED_REWARDS
+        .may_load(deps.storage, draw_id)?
+        .unwrap_or_default();
+    let compounded_lost = compounded.saturating_sub(undelegated.saturating_sub(principal));
+
+    // Read the stored total, querying would count the pending rewards twice
+    let accumulated_rewards = WITHDRAWN_REWARDS
+        .may_load(deps.storage, draw_id)?
+        .unwrap_or_default()
+        .saturating_sub(compounded_lost);
+
+    ACCUMALTED_REWARDS_AT_UNDELEGATION.save(deps.storage, draw_id, &accumulated_rewards)?;
+
+    let total_rewards = accumulated_rewards + draw.bonus_rewards;
+
+    // // Ensure we have rewards to send
+    // if total_rewards.is_zero() {
+    //     return Err(ContractError::NoRewardsToSend {});
+    // }
+
+    // Step 3: Set the winner address in the contract state
+    update_draw(deps.storage, draw_id, |mut draw| -> StdResult<_> {
+        draw.winners = winners.clone();
+        draw.accumulated_rewards = accumulated_rewards;
+        Ok(draw)
+    })?;
+    transition_draw_state(deps.storage, env, draw_id, new_state.clone())?;
+
+    // Step 4: Start the undelegation process for all the tokens of the draw, on every validator
+    let mut messages: Vec<CosmosMsg> = vec![];
+    for (validator, amount) in delegations {
+        if amount.is_zero() {
+            continue;
+        }
+        let undelegate_msg = StakingMsg::Undelegate {
+            validator,
+            amount: CosmosCoin {
+                denom: config.core_denom.clone(),
+                amount,
+            },
+        };
+        messages.push(CosmosMsg::Staking(undelegate_msg));
+    }
+
+    // Step 5: Calculate the timestamp at which the undelegation will be completed
+    let undelegation_period_seconds = query_unbonding_seconds(deps.as_ref(), &config);
+    // Rounded up, the unbonding completes at the block time in nanoseconds plus the period
+    let undelegation_done_timestamp =
+        env.block.time.nanos().div_ceil(1_000_000_000) + undelegation_period_seconds;
+
+    // Step 6: Update the contract state with the future timestamp
+    update_draw(deps.storage, draw_id, |mut draw| -> StdResult<_> {
+        draw.undelegation_done_timestamp = Some(undelegation_done_timestamp);
+        Ok(draw)
+    })?;
+
+    // Return response with all actions
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", action),
+        ("draw_id", &draw_id.to_string()),
+        ("winners", &join_addresses(&winners)),
+        ("rewards_amount", &total_rewards.to_string()),
+        (
+            "undelegation_done_timestamp",
+            &undelegation_done_timestamp.to_string(),
+        ),
+        ("new_state", &format!("{:?}", new_state)),
+    ]))
+}
+
+pub fn execute_send_funds_to_winner(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    draw_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner
+    let config = CONFIG.load(deps.storage)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+
+    assert_owner(deps.storage, &info.sender)?;
+    assert_not_paused(deps.storage, Operation::Payout)?;
+
+    // Step 2: Verify the draw is in the correct state
+    if draw.draw_state != DrawState::WinnerSelectedUndelegationInProcess {
+        return Err(ContractError::InvalidDrawState {
+            expected: DrawState::WinnerSelectedUndelegationInProcess,
+            actual: draw.draw_state,
+        });
+    }
+
+    // Step 3: Check the undelegated funds are back in the contract
+    settle_recovered_principal(deps.branch(), &env, &config, draw_id)?;
+    ensure_payout_liquid(deps.as_ref(), &env, &config, &draw)?;
+
+    // Step 4: Get the winners
+    if draw.winners.is_empty() {
+        return Err(ContractError::NoWinnerSelected {});
+    }
+
+    // Step 5: Calculate total rewards
+    let total_rewards = draw.accumulated_rewards + draw.bonus_rewards;
+
+    // Ensure we have rewards to send
+    if total_rewards.is_zero() {
+        return Err(ContractError::NoRewardsToSend {});
+    }
+
+    // Step 6: Send the prizes not claimed yet and the protocol fee
+    let PrizePayment {
+        messages,
+        payouts,
+        fee,
+        sent,
+    } = pay_prizes(deps.storage, &env, &config, draw_id, None)?;
+    if payouts.is_empty() {
+        return Err(ContractError::PrizeAlreadyPaid {});
+    }
+    ensure_solvent(deps.as_ref(), &env, &config, sent)?;
+    let paid: Uint128 = payouts.iter().map(|(_, amount)| *amount).sum();
+    let winners: Vec<Addr> = payouts.into_iter().map(|(winner, _)| winner).collect();
+
+    // Return response with all actions
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "send_funds_to_winner"),
+        ("draw_id", &draw_id.to_string()),
+        ("winners", &join_addresses(&winners)),
+        ("rewards_amount", &paid.to_string()),
+        ("fee_amount", &fee.to_string()),
+    ]))
+}
+
+pub fn execute_claim_bonus_tokens(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    // Step 1: Bonus tokens are a payout too
+    assert_not_paused(deps.storage, Operation::Payout)?;
+
+    // Step 2: Send the tokens of the denom credited to the caller
+    let amount = BONUS_TOKEN_CLAIMS
+        .may_load(deps.storage, (&info.sender, &denom))?
+        .filter(|amount| !amount.is_zero())
+        .ok_or(ContractError::NoBonusTokensToClaim {})?;
+    BONUS_TOKEN_CLAIMS.remove(deps.storage, (&info.sender, &denom));
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![CosmosCoin {
+                denom: denom.clone(),
+                amount,
+            }],
+        })
+        .add_attributes(vec![
+            ("action", "claim_bonus_tokens"),
+            ("recipient", info.sender.as_str()),
+            ("denom", &denom),
+            ("amount", &amount.to_string()),
+        ]))
+}
+
+pub fn execute_claim_prize(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    draw_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller won the draw
+    assert_not_paused(deps.storage, Operation::Payout)?;
+    let config = CONFIG.load(deps.storage)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    if !draw.winners.contains(&info.sender) {
+        return Err(ContractError::NotAWinner {});
+    }
+
+    // Step 2: Verify the undelegated funds are back in the contract
+    settle_recovered_principal(deps.branch(), &env, &config, draw_id)?;
+    ensure_payout_liquid(deps.as_ref(), &env, &config, &draw)?;
+
+    // Step 3: Send the prize of the caller once
+    let PrizePayment {
+        messages,
+        payouts,
+        fee,
+        sent,
+    } = pay_prizes(deps.storage, &env, &config, draw_id, Some(&info.sender))?;
+    let (_, amount) = payouts
+        .into_iter()
+        .next()
+        .ok_or(ContractError::PrizeAlreadyPaid {})?;
+    ensure_solvent(deps.as_ref(), &env, &config, sent)?;
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "claim_prize"),
+        ("draw_id", &draw_id.to_string()),
+        ("winner", info.sender.as_str()),
+        ("rewards_amount", &amount.to_string()),
+        ("fee_amount", &fee.to_string()),
+    ]))
+}
+
+// The principal recorded at undelegation can still be slashed while it unbonds. Once the
+// undelegation is complete, the first burn or claim measures it again from the liquid balance,
+// less what the other draws and the rewards of this draw are owed, and keeps the lower of both.
+fn settle_recovered_principal(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    draw_id: u64,
+) -> StdResult<()> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let now = env.block.time.seconds();
+    if PRINCIPAL_SETTLED.has(deps.storage, draw_id)
+        || draw
+            .undelegation_done_timestamp
+            .is_none_or(|timestamp| now < timestamp)
+    {
+        return Ok(());
+    }
+    let Some(recorded) = PRINCIPAL_RECOVERED.may_load(deps.storage, draw_id)? else {
+        return Ok(());
+    };
+    // The undelegated principal is only released in the EndBlock after the unbonding
+    // completes, measuring the balance before that would cut the refunds to nothing
+    let validators = draw
+        .validators
+        .iter()
+        .map(|validator| validator.address.as_str())
+        .collect::<Vec<_>>();
+    if query_unbonding_entries(deps.as_ref(), env)?
+        .iter()
+        .any(|unbonding| {
+            validators.contains(&unbonding.validator_address.as_str())
+                && !unbonding.entries.is_empty()
+        })
+    {
+        return Ok(());
+    }
+
+    // Principal of the tickets not burned yet, before and after the recorded slashing
+    let owed = draw.ticket_price.multiply_ratio(
+        ticket_subunits_left(deps.storage, draw_id)?,
+        ticket_unit(&draw),
+    );
+    if !owed.is_zero() {
+        let refunds = scale_refund(deps.storage, draw_id, owed)?;
+        let own = draw_liquid_liabilities(deps.storage, draw_id, now)?;
+        let others = liquid_liabilities(deps.storage, now)?.saturating_sub(own);
+        let liquid = deps
+            .querier
+            .query_balance(&env.contract.address, &config.core_denom)?
+            .amount;
+        let measured = liquid
+            .saturating_sub(others)
+            .saturating_sub(own.saturating_sub(refunds))
+            .multiply_ratio(get_draft_tvl(deps.storage, draw_id)?, owed);
+        PRINCIPAL_RECOVERED.save(deps.storage, draw_id, &recorded.min(measured))?;
+    }
+    PRINCIPAL_SETTLED.save(deps.storage, draw_id, &true)
+}
+
+// Prizes are paid from the undelegated funds, never from the principal still owed to the
+// ticket holders: the undelegation must be complete and the liquid balance must cover
+// everything owed from it, by this draw and by every other draw
+fn ensure_payout_liquid(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    draw: &DrawConfig,
+) -> Result<(), ContractError> {
+    let undelegation_timestamp = draw
+        .undelegation_done_timestamp
+        .ok_or(ContractError::NoUndelegationInProgress {})?;
+    if env.block.time.seconds() < undelegation_timestamp {
+        return Err(ContractError::UndelegationPeriodNotCompleted {
+            current_timestamp: env.block.time.seconds(),
+            undelegation_timestamp,
+        });
+    }
+
+    let required = liquid_liabilities(deps.storage, env.block.time.seconds())?;
+    let available = deps
+        .querier
+        .query_balance(&env.contract.address, &config.core_denom)?
+        .amount;
+    if available < required {
+        return Err(ContractError::InsufficientLiquidity {
+            required,
+            available,
+        });
+    }
+    Ok(())
+}
+
+// CORE the contract holds against what the draws owe. Assets are read from the chain, so an
+// outflow added to the response is not reflected yet and is passed in.
+fn ensure_solvent(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    outflow: Uint128,
+) -> Result<(), ContractError> {
+    ensure_solvent_after(deps, env, config, Uint128::zero(), outflow)
+}
+
+// Same as ensure_solvent when CORE also flows in within the transaction, e.g. rewards withdrawn
+// by an undelegation
+fn ensure_solvent_after(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    inflow: Uint128,
+    outflow: Uint128,
+) -> Result<(), ContractError> {
+    let solvency = query_solvency(deps, env, config)?;
+    let total_assets = solvency.total_assets + inflow;
+    let assets = total_assets.saturating_sub(outflow);
+    if total_assets < outflow || assets < solvency.total_liabilities {
+        return Err(ContractError::Insolvent {
+            assets,
+            liabilities: solvency.total_liabilities,
+        });
+    }
+    Ok(())
+}
+
+// Messages to send, prizes paid by winner and protocol fee of the round
+struct PrizePayment {
+    messages: Vec<CosmosMsg>,
+    payouts: Vec<(Addr, Uint128)>,
+    fee: Uint128,  // Protocol fee of the round
+    sent: Uint128, // CORE sent by the messages
+}
+
+// Pay the prizes of the current round that were not paid yet, to `recipient` only or to every
+// winner. The protocol fee is sent along with the first prize paid and then frozen, so every
+// winner's share is computed from the same amount.
+fn pay_prizes(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    draw_id: u64,
+    recipient: Option<&Addr>,
+) -> Result<PrizePayment, ContractError> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut sent = Uint128::zero();
+
+    // Without winners (a cancelled round) the rewards were refunded or sent to the fallback
+    // recipient, there is no prize to take a fee from
+    if draw.winners.is_empty() {
+        return Ok(PrizePayment {
+            messages,
+            payouts: vec![],
+            fee: Uint128::zero(),
+            sent,
+        });
+    }
+
+    let fee = match FEES_PAID.may_load(storage, draw_id)? {
+        Some(fee) => fee,
+        None => {
+            let fee = protocol_fee(config, draw.accumulated_rewards);
+            if let Some(treasury) = config.treasury.as_ref().filter(|_| !fee.is_zero()) {
+                sent += fee;
+                messages.push(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: treasury.to_string(),
+                    amount: vec![CosmosCoin {
+                        denom: config.core_denom.clone(),
+                        amount: fee,
+                    }],
+                }));
+                TOTAL_FEES_COLLECTED
+                    .update(storage, |total| -> StdResult<_> { Ok(total + fee) })?;
+            }
+            FEES_PAID.save(storage, draw_id, &fee)?;
+            fee
+        }
+    };
+
+    let prize = draw.accumulated_rewards + draw.bonus_rewards - fee;
+    // Bonus tokens are split between the prize tiers like the CORE prize and sent with it
+    let token_prizes: Vec<(String, Vec<(Addr, Uint128)>)> = bonus_tokens(storage, draw_id)?
+        .into_iter()
+        .map(|(denom, total)| {
+            (
+                denom,
+                prize_payouts(total, &draw.prize_tiers_bps, &draw.winners),
+            )
+        })
+        .collect();
+    let mut payouts = vec![];
+    for (winner, amount) in prize_payouts(prize, &draw.prize_tiers_bps, &draw.winners) {
+        if recipient.is_some_and(|recipient| *recipient != winner)
+            || PRIZES_PAID.has(storage, (draw_id, &winner))
+        {
+            continue;
+        }
+        let tokens: Vec<CosmosCoin> = token_prizes
+            .iter()
+            .filter_map(|(denom, prizes)| {
+                prizes
+                    .iter()
+                    .find(|(address, amount)| *address == winner && !amount.is_zero())
+                    .map(|(_, amount)| CosmosCoin {
+                        denom: denom.clone(),
+                        amount: *amount,
+                    })
+            })
+            .collect();
+        PRIZES_PAID.save(storage, (draw_id, &winner), &amount)?;
+        sent += amount;
+        if !amount.is_zero() {
+            messages.push(CosmosMsg::Any(
+                MsgSend {
+                    from_address: env.contract.address.to_string(),
+                    to_address: winner.to_string(),
+                    amount: vec![Coin {
+                        denom: config.core_denom.clone(),
+                        amount: amount.to_string(),
+                    }],
+                }
+                .to_any(),
+            ));
+        }
+        if !tokens.is_empty() {
+            messages.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: winner.to_string(),
+                amount: tokens,
+            }));
+        }
+        payouts.push((winner, amount));
+    }
+
+    Ok(PrizePayment {
+        messages,
+        payouts,
+        fee,
+        sent,
+    })
+}
+
+pub fn execute_burn_tickets(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    draw_id: Option<u64>,
+    number_of_tickets: Uint128,
+    ticket_subunits: Uint128,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the draw is in the correct state
+    assert_not_paused(deps.storage, Operation::Burn)?;
+    let config = CONFIG.load(deps.storage)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    if draw.draw_state != DrawState::WinnerSelectedUndelegationInProcess
+        && draw.draw_state != DrawState::UndelegationCompletedTokensCanBeBurned
+        && draw.draw_state != DrawState::Cancelled
+    {
+        return Err(ContractError::InvalidDrawState {
+            expected: DrawState::UndelegationCompletedTokensCanBeBurned,
+            actual: draw.draw_state,
+        });
+    }
+
+    // A cancelled draw keeps its state, its tickets are refunded once the undelegation is complete
+    if draw.draw_state == DrawState::Cancelled {
+        if let Some(undelegation_timestamp) = draw.undelegation_done_timestamp {
+            if env.block.time.seconds() < undelegation_timestamp {
+                return Err(ContractError::UndelegationPeriodNotCompleted {
+                    current_timestamp: env.block.time.seconds(),
+                    undelegation_timestamp,
+                });
+            }
+        }
+    }
+
     // Check if undelegation period is complete and update state if needed
-    if config.draw_state == DrawState::WinnerSelectedUndelegationInProcess {
-        if let Some(undelegation_timestamp) = config.undelegation_done_timestamp {
+    if draw.draw_state == DrawState::WinnerSelectedUndelegationInProcess {
+        if let Some(undelegation_timestamp) = draw.undelegation_done_timestamp {
             if env.block.time.seconds() >= undelegation_timestamp {
-                CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-                    config.draw_state = DrawState::UndelegationCompletedTokensCanBeBurned;
-                    Ok(config)
-                })?;
+                transition_draw_state(
+                    deps.storage,
+                    &env,
+                    draw_id,
+                    DrawState::UndelegationCompletedTokensCanBeBurned,
+                )?;
             } else {
                 return Err(ContractError::UndelegationPeriodNotCompleted {
                     current_timestamp: env.block.time.seconds(),
@@ -474,18 +1640,31 @@ pub fn execute_burn_tickets(
         }
     }
 
+    // The refunds are scaled by the principal actually recovered
+    settle_recovered_principal(deps.branch(), &env, &config, draw_id)?;
+
     //Step3: Check if the user sent the correct amount of Ticket in the funds based on the number of tickets they want to burn
+    // Fractions of a ticket can only be burned from divisible TICKET tokens
+    let unit = ticket_unit(&draw);
+    if !ticket_subunits.is_zero() && ticket_subunits >= unit {
+        return Err(ContractError::InvalidTicketSubunits {
+            max: unit - Uint128::one(),
+        });
+    }
+    let burned_subunits = number_of_tickets * unit + ticket_subunits;
+    if burned_subunits.is_zero() {
+        return Err(ContractError::InvalidTicketAmount {});
+    }
 
-    let ticket_denom = TICKET_DENOM.load(deps.storage)?;
     let payment = info
         .funds
         .iter()
-        .find(|coin| coin.denom == ticket_denom)
+        .find(|coin| coin.denom == draw.ticket_denom)
         .ok_or(ContractError::NoFunds {})?;
 
-    if payment.amount < number_of_tickets * Uint128::from(10u128).pow(TICKET_PRECISION) {
+    if payment.amount < burned_subunits {
         return Err(ContractError::InsufficientFunds {
-            required: number_of_tickets * Uint128::from(10u128).pow(TICKET_PRECISION),
+            required: burned_subunits,
             provided: payment.amount,
         });
     }
@@ -494,14 +1673,19 @@ pub fn execute_burn_tickets(
     let burn_msg = MsgBurn {
         sender: env.contract.address.to_string(),
         coin: Some(Coin {
-            denom: TICKET_DENOM.load(deps.storage)?,
-            amount: (number_of_tickets * Uint128::from(10u128).pow(TICKET_PRECISION)).to_string(),
+            denom: draw.ticket_denom.clone(),
+            amount: burned_subunits.to_string(),
         }),
     };
 
-    // Step 4: Calculate the refund amount (original investment)
+    // Step 4: Calculate the refund amount (original investment, minus its share of any slashing)
     //We use the users_tickets instead of the requested number of tickets
-    let refund_amount: Uint128 = number_of_tickets * config.ticket_price;
+    let refund_amount: Uint128 =
+        scale_refund(
+            deps.storage,
+            draw_id,
+            draw.ticket_price.multiply_ratio(burned_subunits, unit),
+        )? + cancelled_rewards_share(deps.storage, draw_id, burned_subunits)?;
 
     // Step 5: Send back the COREUM to the user
     let send_refund_msg = CosmosMsg::Bank(BankMsg::Send {
@@ -512,33 +1696,50 @@ pub fn execute_burn_tickets(
         }],
     });
 
-    // Step 6: Update internal state - user tickets and total burned (not ideal in terms of semantics cause tikets can switch hands)
-    // decrease_ticket_holder_primary_market(deps.storage, &info.sender, number_of_tickets)?;
-    increment_tickets_burned(deps.storage, number_of_tickets)?;
-    update_claim(deps.storage, &info.sender, refund_amount)?;
+    // Step 6: Update internal state - total burned and the claim of the burner
+    increment_ticket_subunits_burned(deps.storage, draw_id, burned_subunits, unit)?;
+    update_claim(deps.storage, draw_id, &info.sender, refund_amount)?;
 
     // Step 7: Check if all tickets have been burned, set draw_state=draw_finished if so
+    let draw_id_str = draw_id.to_string();
     let tickets_str = number_of_tickets.to_string();
+    let subunits_str = burned_subunits.to_string();
     let refund_str = refund_amount.to_string();
     let mut attrs = vec![
         ("action", "burn_tickets"),
+        ("draw_id", &draw_id_str),
         ("burner", info.sender.as_str()),
         ("tickets_burned", &tickets_str),
+        ("ticket_subunits_burned", &subunits_str),
         ("refund_amount", &refund_str),
     ];
 
-    if all_tickets_burned(deps.storage)? {
-        CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-            config.draw_state = DrawState::DrawFinished;
-            Ok(config)
-        })?;
+    let next_round_str;
+    let mut prize_messages = vec![];
+    let mut outflow = refund_amount;
+    if all_tickets_burned(deps.storage, draw_id)? {
+        transition_draw_state(deps.storage, &env, draw_id, DrawState::DrawFinished)?;
         attrs.push(("new_state", "DrawFinished"));
+
+        // Step 8: Recurring draws pay the unclaimed prizes, archive the round and reopen ticket
+        // sales right away, unless payouts are paused
+        if draw.recurring && !is_paused(deps.storage, &Operation::Payout)? {
+            let payment = pay_prizes(deps.storage, &env, &config, draw_id, None)?;
+            prize_messages = payment.messages;
+            outflow += payment.sent;
+            next_round_str = start_next_round(deps.storage, draw_id, &env.block)?.to_string();
+            attrs.push(("next_round", &next_round_str));
+        }
     }
 
+    // Step 9: The refund and prizes must leave what the other ticket holders are owed
+    ensure_solvent(deps.as_ref(), &env, &config, outflow)?;
+
     // Return the response with all actions
     Ok(Response::new()
         .add_message(CosmosMsg::Any(burn_msg.to_any()))
         .add_message(send_refund_msg)
+        .add_messages(prize_messages)
         .add_attributes(attrs))
 }
 
@@ -546,172 +1747,1069 @@ pub fn execute_add_bonus_reward(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    draw_id: Option<u64>,
     amount: Uint128,
+    memo: Option<String>,
+    logo_uri: Option<String>,
 ) -> Result<Response, ContractError> {
-    // Check if the user sent CORE tokens
+    // Step 1: Check the user sent enough CORE tokens. Any other token allowed by the owner is
+    // a bonus in full
     let config = CONFIG.load(deps.storage)?;
-    let sent_funds = info
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    if let Some(coin) = info
+        .funds
+        .iter()
+        .find(|coin| coin.denom != config.core_denom && !config.bonus_denoms.contains(&coin.denom))
+    {
+        return Err(ContractError::BonusDenomNotAllowed {
+            denom: coin.denom.clone(),
+        });
+    }
+    let tokens: Vec<CosmosCoin> = info
+        .funds
+        .iter()
+        .filter(|coin| coin.denom != config.core_denom && !coin.amount.is_zero())
+        .cloned()
+        .collect();
+    let sent_amount = info
         .funds
         .iter()
         .find(|coin| coin.denom == config.core_denom)
-        .ok_or(ContractError::NoFunds {})?;
+        .map(|coin| coin.amount)
+        .unwrap_or_default();
 
-    // Verify the amount matches
-    if sent_funds.amount < amount {
+    if sent_amount < amount {
         return Err(ContractError::InsufficientFunds {
             required: amount,
-            provided: sent_funds.amount,
+            provided: sent_amount,
+        });
+    }
+    if amount.is_zero() && tokens.is_empty() {
+        return Err(ContractError::NoFunds {});
+    }
+    let refund_amount = sent_amount - amount;
+
+    if [&memo, &logo_uri].iter().any(|info| {
+        info.as_ref()
+            .is_some_and(|info| info.len() > MAX_SPONSOR_INFO_LENGTH)
+    }) {
+        return Err(ContractError::SponsorInfoTooLong {
+            max_length: MAX_SPONSOR_INFO_LENGTH,
         });
     }
 
-    // Add to the bonus rewards
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.bonus_rewards += amount;
-        Ok(config)
+    // Step 2: Bonus rewards can be added until the prizes start being paid
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    if matches!(
+        draw.draw_state,
+        DrawState::DrawFinished | DrawState::Cancelled
+    ) || FEES_PAID.has(deps.storage, draw_id)
+    {
+        return Err(ContractError::BonusDepositsClosed {});
+    }
+
+    // Step 3: Add to the bonus rewards and credit the sponsor
+    update_draw(deps.storage, draw_id, |mut draw| -> StdResult<_> {
+        draw.bonus_rewards += amount;
+        Ok(draw)
     })?;
+    for token in &tokens {
+        BONUS_TOKENS.update(
+            deps.storage,
+            (draw_id, &token.denom),
+            |total| -> StdResult<_> { Ok(total.unwrap_or_default() + token.amount) },
+        )?;
+    }
+    BONUS_CONTRIBUTORS.update(
+        deps.storage,
+        (draw_id, &info.sender),
+        |contribution| -> StdResult<_> {
+            let mut contribution = contribution.unwrap_or(BonusContribution {
+                amount: Uint128::zero(),
+                memo: None,
+                logo_uri: None,
+                tokens: vec![],
+            });
+            for token in &tokens {
+                match contribution
+                    .tokens
+                    .iter_mut()
+                    .find(|sponsored| sponsored.denom == token.denom)
+                {
+                    Some(sponsored) => sponsored.amount += token.amount,
+                    None => contribution.tokens.push(token.clone()),
+                }
+            }
+            contribution.tokens.sort_by(|a, b| a.denom.cmp(&b.denom));
+            Ok(BonusContribution {
+                amount: contribution.amount + amount,
+                memo: memo.or(contribution.memo),
+                logo_uri: logo_uri.or(contribution.logo_uri),
+                tokens: contribution.tokens,
+            })
+        },
+    )?;
+
+    // Step 4: Send back anything above the amount
+    let mut response = Response::new();
+    if !refund_amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![CosmosCoin {
+                denom: config.core_denom,
+                amount: refund_amount,
+            }],
+        });
+    }
 
     // Return success response
-    Ok(Response::new().add_attributes(vec![
+    Ok(response.add_attributes(vec![
         ("action", "add_bonus_reward"),
+        ("draw_id", draw_id.to_string().as_str()),
         ("sender", info.sender.to_string().as_str()),
         ("amount", amount.to_string().as_str()),
+        ("tokens", join_coins(&tokens).as_str()),
+        ("refund_amount", refund_amount.to_string().as_str()),
     ]))
 }
 
 pub fn execute_update_draw_state(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
+    draw_id: Option<u64>,
     new_state: DrawState,
 ) -> Result<Response, ContractError> {
     // Verify the caller is the owner
 
     assert_owner(deps.storage, &info.sender)?;
-    // Update the state
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.draw_state = new_state.clone();
-        Ok(config)
-    })?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+
+    // Winners are only set by selecting or revealing them, and cancelling undelegates the draw
+    if new_state == DrawState::WinnerSelectedUndelegationInProcess {
+        return Err(ContractError::UseSelectWinnerFunction {});
+    }
+    if new_state == DrawState::Cancelled {
+        return Err(ContractError::InvalidStateTransition {
+            from: format!("{:?}", DRAWS.load(deps.storage, draw_id)?.draw_state),
+            to: format!("{:?}", new_state),
+        });
+    }
+
+    // Update the state, only along the lifecycle and once its conditions are met
+    transition_draw_state(deps.storage, &env, draw_id, new_state.clone())?;
 
     // Return success response
     Ok(Response::new().add_attributes(vec![
         ("action", "update_draw_state"),
+        ("draw_id", &draw_id.to_string()),
         ("new_state", &format!("{:?}", new_state)),
     ]))
 }
 
-pub fn execute_send_funds(
+pub fn execute_schedule_emergency_state_change(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    recipient: String,
-    amount: Uint128,
+    draw_id: Option<u64>,
+    new_state: DrawState,
 ) -> Result<Response, ContractError> {
-    // Verify the caller is the owner
-    let config = CONFIG.load(deps.storage)?;
+    // Step 1: Verify the caller is the owner
     assert_owner(deps.storage, &info.sender)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+
+    if new_state == draw.draw_state {
+        return Err(ContractError::InvalidStateTransition {
+            from: format!("{:?}", draw.draw_state),
+            to: format!("{:?}", new_state),
+        });
+    }
+    ensure_emergency_target(&draw.draw_state, &new_state)?;
+
+    // Step 2: Schedule the change, replacing any change scheduled before
+    let change = EmergencyStateChange {
+        from_state: draw.draw_state,
+        new_state,
+        executable_at: env.block.time.seconds() + EMERGENCY_TIMELOCK_SECONDS,
+    };
+    EMERGENCY_STATE_CHANGE.save(deps.storage, draw_id, &change)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "schedule_emergency_state_change"),
+        ("draw_id", &draw_id.to_string()),
+        ("from_state", &format!("{:?}", change.from_state)),
+        ("new_state", &format!("{:?}", change.new_state)),
+        ("executable_at", &change.executable_at.to_string()),
+    ]))
+}
+
+// Only cancelling undelegates the principal of a draw, forcing any other state out of the
+// delegated states would strand it with the validators, and forcing an undelegated draw back
+// would count a principal that is no longer delegated
+fn ensure_emergency_target(from: &DrawState, to: &DrawState) -> Result<(), ContractError> {
+    let leaves =
+        is_principal_delegated(from) && !is_principal_delegated(to) && to != &DrawState::Cancelled;
+    let returns = !is_principal_delegated(from) && is_principal_delegated(to);
+    if leaves || returns {
+        return Err(ContractError::EmergencyStateChangeStrandsPrincipal {});
+    }
+    Ok(())
+}
+
+pub fn execute_emergency_state_change(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    draw_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let change = EMERGENCY_STATE_CHANGE
+        .may_load(deps.storage, draw_id)?
+        .ok_or(ContractError::NoEmergencyStateChange {})?;
+
+    // Step 2: Verify the timelock expired and the draw did not move since scheduling
+    if env.block.time.seconds() < change.executable_at {
+        return Err(ContractError::EmergencyTimelockNotExpired {
+            current_timestamp: env.block.time.seconds(),
+            executable_at: change.executable_at,
+        });
+    }
+    if draw.draw_state != change.from_state {
+        return Err(ContractError::InvalidStateTransition {
+            from: format!("{:?}", draw.draw_state),
+            to: format!("{:?}", change.new_state),
+        });
+    }
+
+    ensure_emergency_target(&change.from_state, &change.new_state)?;
+
+    // Step 3: Cancelling a delegated draw undelegates and refunds it like any cancelled draw
+    if is_principal_delegated(&draw.draw_state) && change.new_state == DrawState::Cancelled {
+        let response = cancel_draw(
+            deps.branch(),
+            &env,
+            draw_id,
+            draw,
+            "execute_emergency_state_change",
+        )?;
+        EMERGENCY_STATE_CHANGE.remove(deps.storage, draw_id);
+        return Ok(response);
+    }
+
+    // Step 4: Otherwise force the new state
+    EMERGENCY_STATE_CHANGE.remove(deps.storage, draw_id);
+    let sold_out = change.new_state == DrawState::TicketsSoldOutAccumulationInProgress;
+    if sold_out {
+        record_sell_out_block(deps.storage, &env, draw_id)?;
+    }
+    update_draw(deps.storage, draw_id, |mut draw| -> StdResult<_> {
+        if sold_out {
+            draw.sold_out_at = Some(env.block.time.seconds());
+        }
+        draw.draw_state = change.new_state.clone();
+        Ok(draw)
+    })?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "execute_emergency_state_change"),
+        ("draw_id", &draw_id.to_string()),
+        ("new_state", &format!("{:?}", change.new_state)),
+    ]))
+}
+
+pub fn execute_cancel_emergency_state_change(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    draw_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    if !EMERGENCY_STATE_CHANGE.has(deps.storage, draw_id) {
+        return Err(ContractError::NoEmergencyStateChange {});
+    }
+    EMERGENCY_STATE_CHANGE.remove(deps.storage, draw_id);
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "cancel_emergency_state_change"),
+        ("draw_id", &draw_id.to_string()),
+    ]))
+}
+
+pub fn execute_pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<Operation>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner or the guardian
+    let config = CONFIG.load(deps.storage)?;
+    if config.guardian.as_ref() != Some(&info.sender) {
+        assert_owner(deps.storage, &info.sender)?;
+    }
+
+    // Step 2: Pause the operations, the others keep their flag
+    let paused = update_pause_flags(deps.storage, &operations, true)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "pause"),
+        ("sender", info.sender.as_str()),
+        ("paused", &format!("{:?}", paused)),
+    ]))
+}
+
+pub fn execute_unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operations: Vec<Operation>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner, the guardian cannot unpause
+    assert_owner(deps.storage, &info.sender)?;
+
+    // Step 2: Resume the operations, the others keep their flag
+    let paused = update_pause_flags(deps.storage, &operations, false)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "unpause"),
+        ("paused", &format!("{:?}", paused)),
+    ]))
+}
+
+fn update_pause_flags(
+    storage: &mut dyn Storage,
+    operations: &[Operation],
+    paused: bool,
+) -> StdResult<PauseFlags> {
+    let mut flags = PAUSE_FLAGS.may_load(storage)?.unwrap_or_default();
+    for operation in operations {
+        match operation {
+            Operation::Buy => flags.buy = paused,
+            Operation::Burn => flags.burn = paused,
+            Operation::Payout => flags.payout = paused,
+            Operation::AdminSend => flags.admin_send = paused,
+        }
+    }
+    PAUSE_FLAGS.save(storage, &flags)?;
+    Ok(flags)
+}
+
+pub fn execute_set_guardian(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    // Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+
+    let guardian = guardian
+        .map(|guardian| deps.api.addr_validate(&guardian))
+        .transpose()?;
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.guardian = guardian.clone();
+        Ok(config)
+    })?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "set_guardian"),
+        (
+            "guardian",
+            &guardian
+                .map(|guardian| guardian.to_string())
+                .unwrap_or_default(),
+        ),
+    ]))
+}
+
+pub fn execute_set_min_accumulation_period(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    seconds: u64,
+) -> Result<Response, ContractError> {
+    // Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+
+    // Applies to draws accumulating rewards right now too
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.min_accumulation_seconds = seconds;
+        Ok(config)
+    })?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "set_min_accumulation_period"),
+        ("min_accumulation_seconds", &seconds.to_string()),
+    ]))
+}
+
+pub fn execute_update_fee_config(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    fee_bps: u64,
+    treasury: Option<String>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+
+    // Step 2: Validate and save the fee, it applies to prizes sent from now on
+    let treasury = validate_fee(deps.as_ref(), fee_bps, treasury)?;
+    let config = CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.fee_bps = fee_bps;
+        config.treasury = treasury;
+        Ok(config)
+    })?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_fee_config"),
+        ("fee_bps", &config.fee_bps.to_string()),
+        (
+            "treasury",
+            &config
+                .treasury
+                .map(|treasury| treasury.to_string())
+                .unwrap_or_default(),
+        ),
+    ]))
+}
+
+pub fn execute_set_bonus_denoms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denoms: Vec<String>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // Step 2: Every denom is paid in its own loop iteration, the list is kept short. CORE is
+    // always accepted and the TICKET tokens of the draws never are.
+    let mut denoms = denoms;
+    denoms.sort();
+    denoms.dedup();
+    if denoms.len() > MAX_BONUS_DENOMS {
+        return Err(ContractError::TooManyBonusDenoms {
+            max: MAX_BONUS_DENOMS,
+        });
+    }
+    let ticket_suffix = format!("-{}", env.contract.address);
+    if let Some(denom) = denoms
+        .iter()
+        .find(|denom| **denom == config.core_denom || denom.ends_with(&ticket_suffix))
+    {
+        return Err(ContractError::UnexpectedDenom {
+            denom: denom.clone(),
+        });
+    }
+
+    // Step 3: Save the denoms, the bonus tokens already added are still paid
+    config.bonus_denoms = denoms;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "set_bonus_denoms"),
+        ("bonus_denoms", &config.bonus_denoms.join(",")),
+    ]))
+}
+
+pub fn execute_send_funds(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner
+    let config = CONFIG.load(deps.storage)?;
+    assert_owner(deps.storage, &info.sender)?;
+    assert_not_paused(deps.storage, Operation::AdminSend)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    // Step 2: Only the surplus not reserved by queued withdrawals can be sent
+    let pending = pending_withdrawals_total(deps.storage)?;
+    ensure_solvent(deps.as_ref(), &env, &config, amount + pending)?;
+
+    // Step 3: Small amounts are sent right away
+    if amount <= config.withdrawal_threshold {
+        return Ok(Response::new()
+            .add_message(withdrawal_msg(&env, &config, &recipient, amount))
+            .add_attribute("action", "send_funds")
+            .add_attribute("recipient", recipient.as_str())
+            .add_attribute("amount", amount.to_string()));
+    }
+
+    // Step 4: Larger ones are queued, so users can exit before they are executed
+    let id = LATEST_WITHDRAWAL_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    LATEST_WITHDRAWAL_ID.save(deps.storage, &id)?;
+    let withdrawal = PendingWithdrawal {
+        id,
+        recipient,
+        amount,
+        executable_at: env.block.time.seconds() + config.withdrawal_timelock_seconds,
+    };
+    PENDING_WITHDRAWALS.save(deps.storage, id, &withdrawal)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "queue_withdrawal"),
+        ("withdrawal_id", &id.to_string()),
+        ("recipient", withdrawal.recipient.as_str()),
+        ("amount", &amount.to_string()),
+        ("executable_at", &withdrawal.executable_at.to_string()),
+    ]))
+}
+
+pub fn execute_execute_withdrawal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    withdrawal_id: u64,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the caller is the owner
+    let config = CONFIG.load(deps.storage)?;
+    assert_owner(deps.storage, &info.sender)?;
+    assert_not_paused(deps.storage, Operation::AdminSend)?;
+
+    // Step 2: Verify the timelock expired
+    let withdrawal = PENDING_WITHDRAWALS
+        .may_load(deps.storage, withdrawal_id)?
+        .ok_or(ContractError::WithdrawalNotFound {})?;
+    if env.block.time.seconds() < withdrawal.executable_at {
+        return Err(ContractError::WithdrawalTimelockNotExpired {
+            current_timestamp: env.block.time.seconds(),
+            executable_at: withdrawal.executable_at,
+        });
+    }
+
+    // Step 3: The amount must still be surplus, ticket holders may have bought since
+    PENDING_WITHDRAWALS.remove(deps.storage, withdrawal_id);
+    ensure_solvent(deps.as_ref(), &env, &config, withdrawal.amount)?;
 
-    // Create the send message
-    let recipient_str = recipient.clone();
-    let send_msg = CosmosMsg::Any(
-        MsgSend {
-            from_address: env.contract.address.to_string(),
-            to_address: recipient,
-            amount: vec![Coin {
-                denom: config.core_denom.clone(),
-                amount: amount.to_string(),
-            }],
-        }
-        .to_any(),
-    );
-
     Ok(Response::new()
-        .add_message(send_msg)
-        .add_attribute("action", "send_funds")
-        .add_attribute("recipient", &recipient_str)
-        .add_attribute("amount", &amount.to_string()))
+        .add_message(withdrawal_msg(
+            &env,
+            &config,
+            &withdrawal.recipient,
+            withdrawal.amount,
+        ))
+        .add_attributes(vec![
+            ("action", "execute_withdrawal"),
+            ("withdrawal_id", &withdrawal_id.to_string()),
+            ("recipient", withdrawal.recipient.as_str()),
+            ("amount", &withdrawal.amount.to_string()),
+        ]))
+}
+
+pub fn execute_cancel_withdrawal(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    withdrawal_id: u64,
+) -> Result<Response, ContractError> {
+    // Verify the caller is the owner or the guardian
+    let config = CONFIG.load(deps.storage)?;
+    if config.guardian.as_ref() != Some(&info.sender) {
+        assert_owner(deps.storage, &info.sender)?;
+    }
+    if !PENDING_WITHDRAWALS.has(deps.storage, withdrawal_id) {
+        return Err(ContractError::WithdrawalNotFound {});
+    }
+    PENDING_WITHDRAWALS.remove(deps.storage, withdrawal_id);
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "cancel_withdrawal"),
+        ("withdrawal_id", &withdrawal_id.to_string()),
+        ("sender", info.sender.as_str()),
+    ]))
+}
+
+fn withdrawal_msg(env: &Env, config: &Config, recipient: &Addr, amount: Uint128) -> CosmosMsg {
+    CosmosMsg::Any(
+        MsgSend {
+            from_address: env.contract.address.to_string(),
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: config.core_denom.clone(),
+                amount: amount.to_string(),
+            }],
+        }
+        .to_any(),
+    )
 }
 
 pub fn execute_set_undelegation_timestamp(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    draw_id: Option<u64>,
     timestamp: u64,
 ) -> Result<Response, ContractError> {
     // Verify the caller is the owner
-    let config = CONFIG.load(deps.storage)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
     assert_owner(deps.storage, &info.sender)?;
 
-    // Verify the draw is in the correct state
-    if config.draw_state != DrawState::WinnerSelectedUndelegationInProcess {
-        return Err(ContractError::InvalidDrawState {
-            expected: DrawState::WinnerSelectedUndelegationInProcess,
-            actual: config.draw_state,
-        });
+    // Verify the draw is in the correct state
+    if draw.draw_state != DrawState::WinnerSelectedUndelegationInProcess {
+        return Err(ContractError::InvalidDrawState {
+            expected: DrawState::WinnerSelectedUndelegationInProcess,
+            actual: draw.draw_state,
+        });
+    }
+
+    // The undelegation cannot complete earlier than computed from the unbonding time, the
+    // timestamp can only be moved later
+    let minimum = draw
+        .undelegation_done_timestamp
+        .ok_or(ContractError::NoUndelegationInProgress {})?;
+    if timestamp < minimum {
+        return Err(ContractError::UndelegationTimestampTooEarly { minimum });
+    }
+
+    // Update the timestamp
+    update_draw(deps.storage, draw_id, |mut draw| -> StdResult<_> {
+        draw.undelegation_done_timestamp = Some(timestamp);
+        Ok(draw)
+    })?;
+
+    // Return success response
+    Ok(Response::new().add_attributes(vec![
+        ("action", "set_undelegation_timestamp"),
+        ("draw_id", &draw_id.to_string()),
+        ("timestamp", &timestamp.to_string()),
+    ]))
+}
+
+pub fn transfer_token_admin(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    draw_id: Option<u64>,
+    account: String,
+) -> Result<Response, ContractError> {
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let denom = DRAWS.load(deps.storage, draw_id)?.ticket_denom;
+
+    assert_owner(deps.storage, &_info.sender)?;
+
+    let transfer_admin = MsgTransferAdmin {
+        sender: env.contract.address.to_string(),
+        account,
+        denom: denom.clone(),
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer_admin")
+        .add_attribute("denom", denom)
+        .add_message(CosmosMsg::Any(transfer_admin.to_any())))
+}
+
+pub fn execute_start_next_round(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    draw_id: Option<u64>,
+    params: Option<RoundParams>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the draw is finished
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    if draw.draw_state != DrawState::DrawFinished {
+        return Err(ContractError::InvalidDrawState {
+            expected: DrawState::DrawFinished,
+            actual: draw.draw_state,
+        });
+    }
+
+    // Step 2: Anyone can restart a recurring draw as is, restarting a draw that is not
+    // recurring or changing its parameters is admin only
+    if params.is_some() || !draw.recurring {
+        assert_owner(deps.storage, &info.sender)?;
+    }
+    if let Some(params) = params {
+        if params.total_tickets.is_some_and(|t| t.is_zero()) {
+            return Err(ContractError::InvalidTicketAmount {});
+        }
+        if params.ticket_price.is_some_and(|p| p.is_zero()) {
+            return Err(ContractError::InvalidTicketPrice {});
+        }
+        let prize_tiers_bps = params
+            .prize_tiers_bps
+            .map(validate_prize_tiers)
+            .transpose()?;
+        validate_sales_deadline(&env, params.sales_deadline)?;
+        validate_sales_close(
+            &env,
+            params.sales_close.as_ref(),
+            params.min_tickets.unwrap_or(draw.min_tickets),
+            params.total_tickets.unwrap_or(draw.total_tickets),
+        )?;
+
+        update_draw(deps.storage, draw_id, |mut draw| -> StdResult<_> {
+            draw.total_tickets = params.total_tickets.unwrap_or(draw.total_tickets);
+            draw.ticket_price = params.ticket_price.unwrap_or(draw.ticket_price);
+            draw.max_tickets_per_user = params
+                .max_tickets_per_user
+                .unwrap_or(draw.max_tickets_per_user);
+            draw.recurring = params.recurring.unwrap_or(draw.recurring);
+            draw.sales_duration = params
+                .sales_deadline
+                .map(|deadline| deadline - env.block.time.seconds());
+            draw.sales_close_offset = params
+                .sales_close
+                .as_ref()
+                .map(|close| close.offset_from(&env.block));
+            draw.min_tickets = params.min_tickets.unwrap_or(draw.min_tickets);
+            if let Some(prize_tiers_bps) = prize_tiers_bps {
+                draw.prize_tiers_bps = prize_tiers_bps;
+            }
+            Ok(draw)
+        })?;
+    }
+
+    // Step 3: Pay the unclaimed prizes, archive the finished round and reopen ticket sales
+    assert_not_paused(deps.storage, Operation::Payout)?;
+    let config = CONFIG.load(deps.storage)?;
+    let payment = pay_prizes(deps.storage, &env, &config, draw_id, None)?;
+    let next_round = start_next_round(deps.storage, draw_id, &env.block)?;
+    ensure_solvent(deps.as_ref(), &env, &config, payment.sent)?;
+
+    Ok(Response::new()
+        .add_messages(payment.messages)
+        .add_attributes(vec![
+            ("action", "start_next_round"),
+            ("draw_id", &draw_id.to_string()),
+            ("finished_round", &draw.round.to_string()),
+            ("next_round", &next_round.to_string()),
+        ]))
+}
+
+pub fn execute_withdraw_rewards(
+    mut deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // Step 1: Find every validator holding principal of a draw
+    let config = CONFIG.load(deps.storage)?;
+    let mut validators: Vec<String> = vec![];
+    let draw_ids = DRAWS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for draw_id in draw_ids {
+        for (validator, _) in draw_delegations(deps.storage, draw_id)? {
+            if !validators.contains(&validator) {
+                validators.push(validator);
+            }
+        }
+    }
+
+    // Step 2: Credit the pending rewards to the draws and withdraw them
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut total_withdrawn = Uint128::zero();
+    for validator in validators {
+        let rewards =
+            withdraw_validator_rewards(deps.branch(), &env, &config.core_denom, &validator)?;
+        if rewards.is_zero() {
+            continue;
+        }
+        total_withdrawn += rewards;
+        messages.push(CosmosMsg::Distribution(
+            DistributionMsg::WithdrawDelegatorReward { validator },
+        ));
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "withdraw_rewards"),
+        ("amount", &total_withdrawn.to_string()),
+    ]))
+}
+
+pub fn execute_compound(
+    mut deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    draw_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Step 1: Verify the draw is accumulating rewards
+    let config = CONFIG.load(deps.storage)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    if draw.draw_state != DrawState::TicketsSoldOutAccumulationInProgress {
+        return Err(ContractError::InvalidDrawState {
+            expected: DrawState::TicketsSoldOutAccumulationInProgress,
+            actual: draw.draw_state,
+        });
+    }
+
+    // Step 2: Withdraw the pending rewards of every validator the draw delegates to or will
+    // delegate to, once per validator so that no reward is credited twice
+    let mut validators: Vec<String> = draw_delegations(deps.storage, draw_id)?
+        .into_iter()
+        .map(|(validator, _)| validator)
+        .collect();
+    for v in &draw.validators {
+        if !validators.contains(&v.address) {
+            validators.push(v.address.clone());
+        }
+    }
+    let mut messages: Vec<CosmosMsg> = vec![];
+    for validator in validators {
+        let rewards =
+            withdraw_validator_rewards(deps.branch(), &env, &config.core_denom, &validator)?;
+        if !rewards.is_zero() {
+            messages.push(CosmosMsg::Distribution(
+                DistributionMsg::WithdrawDelegatorReward { validator },
+            ));
+        }
+    }
+
+    // Step 3: Delegate the withdrawn rewards of the draw that are not compounded yet
+    let withdrawn = WITHDRAWN_REWARDS
+        .may_load(deps.storage, draw_id)?
+        .unwrap_or_default();
+    let compounded = COMPOUNDED_REWARDS
+        .may_load(deps.storage, draw_id)?
+        .unwrap_or_default();
+    let to_compound = withdrawn.saturating_sub(compounded);
+    if to_compound.is_zero() {
+        return Err(ContractError::NothingToCompound {});
+    }
+
+    for (validator, amount) in split_by_weight(to_compound, &draw.validators) {
+        record_delegation(deps.storage, draw_id, &validator, amount)?;
+        messages.push(CosmosMsg::Staking(StakingMsg::Delegate {
+            validator,
+            amount: CosmosCoin {
+                denom: config.core_denom.clone(),
+                amount,
+            },
+        }));
     }
 
-    // Update the timestamp
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.undelegation_done_timestamp = Some(timestamp);
-        Ok(config)
-    })?;
+    // Step 4: Track the compounded rewards apart from the principal
+    let total_compounded = compounded + to_compound;
+    COMPOUNDED_REWARDS.save(deps.storage, draw_id, &total_compounded)?;
 
-    // Return success response
-    Ok(Response::new().add_attributes(vec![
-        ("action", "set_undelegation_timestamp"),
-        ("timestamp", &timestamp.to_string()),
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "compound"),
+        ("draw_id", &draw_id.to_string()),
+        ("amount", &to_compound.to_string()),
+        ("total_compounded", &total_compounded.to_string()),
     ]))
 }
 
-pub fn transfer_token_admin(
-    deps: DepsMut,
+pub fn execute_redelegate(
+    mut deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
-    account: String,
+    info: MessageInfo,
+    draw_id: Option<u64>,
+    from_validator: Option<String>,
+    to_validator: String,
 ) -> Result<Response, ContractError> {
-    let denom = TICKET_DENOM.load(deps.storage)?;
+    // Step 1: Verify the caller is the owner
+    assert_owner(deps.storage, &info.sender)?;
+    let config = CONFIG.load(deps.storage)?;
+    let draw_id = resolve_draw_id(deps.storage, draw_id)?;
+
+    // Step 2: Find the delegations of the draw to move, all of them if no source is given
+    let delegations: Vec<(String, Uint128)> = query_draw_delegations(deps.as_ref(), &env, draw_id)?
+        .into_iter()
+        .filter(|(validator, amount)| {
+            *validator != to_validator
+                && !amount.is_zero()
+                && from_validator.as_ref().is_none_or(|from| from == validator)
+        })
+        .collect();
+    if delegations.is_empty() {
+        return Err(ContractError::NoDelegationToMove {});
+    }
 
-    assert_owner(deps.storage, &_info.sender)?;
+    // Step 3: Redelegate and move the principal of the draw to the new validator
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut events: Vec<Event> = vec![];
+    withdraw_validator_rewards(deps.branch(), &env, &config.core_denom, &to_validator)?;
+    for (src_validator, amount) in delegations {
+        // Redelegating withdraws the pending rewards of both validators
+        withdraw_validator_rewards(deps.branch(), &env, &config.core_denom, &src_validator)?;
+        let principal = DRAW_DELEGATIONS.load(deps.storage, (draw_id, &src_validator))?;
+        DRAW_DELEGATIONS.remove(deps.storage, (draw_id, &src_validator));
+        record_delegation(deps.storage, draw_id, &to_validator, principal)?;
+
+        messages.push(CosmosMsg::Staking(StakingMsg::Redelegate {
+            src_validator: src_validator.clone(),
+            dst_validator: to_validator.clone(),
+            amount: CosmosCoin {
+                denom: config.core_denom.clone(),
+                amount,
+            },
+        }));
+        events.push(Event::new("redelegate").add_attributes(vec![
+            ("draw_id", draw_id.to_string()),
+            ("src_validator", src_validator),
+            ("dst_validator", to_validator.clone()),
+            ("amount", amount.to_string()),
+            ("principal", principal.to_string()),
+        ]));
+    }
 
-    let transfer_admin = MsgTransferAdmin {
-        sender: env.contract.address.to_string(),
-        account,
-        denom: denom.clone(),
-    };
+    // Step 4: New purchases are staked with the new validator, taking over the moved weight
+    update_draw(deps.storage, draw_id, |mut draw| -> StdResult<_> {
+        let mut moved_weight = 0;
+        draw.validators.retain(|v| {
+            let moved = v.address != to_validator
+                && from_validator
+                    .as_ref()
+                    .is_none_or(|from| *from == v.address);
+            if moved {
+                moved_weight += v.weight;
+            }
+            !moved
+        });
+        match draw
+            .validators
+            .iter_mut()
+            .find(|v| v.address == to_validator)
+        {
+            Some(v) => v.weight += moved_weight,
+            None => draw.validators.push(WeightedValidator {
+                address: to_validator.clone(),
+                weight: moved_weight.max(1),
+            }),
+        }
+        Ok(draw)
+    })?;
 
     Ok(Response::new()
-        .add_attribute("method", "transfer_admin")
-        .add_attribute("denom", denom)
-        .add_message(CosmosMsg::Any(transfer_admin.to_any())))
+        .add_messages(messages)
+        .add_events(events)
+        .add_attributes(vec![
+            ("action", "redelegate"),
+            ("draw_id", &draw_id.to_string()),
+            ("to_validator", &to_validator),
+        ]))
+}
+
+fn join_coins(coins: &[CosmosCoin]) -> String {
+    coins
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn join_addresses(addresses: &[Addr]) -> String {
+    addresses
+        .iter()
+        .map(|addr| addr.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let resolve = |draw_id: Option<u64>| resolve_draw_id(deps.storage, draw_id);
+
     match msg {
-        QueryMsg::Balance { account } => to_json_binary(&query_ticket_balance(deps, account)?),
-        QueryMsg::GetParticipants {} => to_json_binary(&query_participants_primary_market(deps)?),
-        QueryMsg::GetWinner {} => to_json_binary(&query_winner(deps)?),
-        QueryMsg::GetCurrentState {} => to_json_binary(&query_current_state(deps)?),
-        QueryMsg::GetNumberOfTicketsSold {} => to_json_binary(&query_number_of_tickets_sold(deps)?),
-        QueryMsg::GetBonusRewards {} => to_json_binary(&query_bonus_rewards(deps)?),
-        QueryMsg::GetAccumulatedRewards {} => {
-            to_json_binary(&query_accumulated_rewards(deps, &_env)?)
-        }
-        QueryMsg::GetAccumulatedRewardsAtUndelegation {} => {
-            to_json_binary(&query_accumulated_rewards_at_undelegation(deps)?)
-        }
-        QueryMsg::GetDraftTvl {} => to_json_binary(&query_draft_tvl(deps)?),
-        QueryMsg::GetTicketHolders {} => to_json_binary(&query_ticket_holders(deps)?),
-        QueryMsg::GetUserNumberOfTickets { address } => {
-            to_json_binary(&query_user_number_of_tickets(deps, address)?)
-        }
-        QueryMsg::GetUserWinChance { address } => {
-            to_json_binary(&query_user_win_chance(deps, address)?)
-        }
-        QueryMsg::GetTotalTicketsBurned {} => to_json_binary(&query_total_tickets_burned(deps)?),
-        QueryMsg::GetClaims { address } => to_json_binary(&query_claims(deps, address)?),
-        QueryMsg::GetDelegatedAmount {} => to_json_binary(&query_delegated_amount(deps, &_env)?),
+        QueryMsg::Balance { draw_id, account } => {
+            to_json_binary(&query_ticket_balance(deps, resolve(draw_id)?, account)?)
+        }
+        QueryMsg::GetParticipants { draw_id } => {
+            to_json_binary(&query_participants(deps, &_env, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetWinner { draw_id } => to_json_binary(&query_winner(deps, resolve(draw_id)?)?),
+        QueryMsg::GetCurrentState { draw_id } => {
+            to_json_binary(&query_current_state(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetNumberOfTicketsSold { draw_id } => {
+            to_json_binary(&query_number_of_tickets_sold(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetBonusRewards { draw_id } => {
+            to_json_binary(&query_bonus_rewards(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetBonusContributors {
+            draw_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_bonus_contributors(
+            deps,
+            resolve(draw_id)?,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetBonusTokenClaims { address } => {
+            to_json_binary(&query_bonus_token_claims(deps, address)?)
+        }
+        QueryMsg::GetAccumulatedRewards { draw_id } => {
+            to_json_binary(&query_accumulated_rewards(deps, &_env, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetAccumulatedRewardsAtUndelegation { draw_id } => to_json_binary(
+            &query_accumulated_rewards_at_undelegation(deps, resolve(draw_id)?)?,
+        ),
+        QueryMsg::GetDraftTvl { draw_id } => {
+            to_json_binary(&query_draft_tvl(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetTicketHolders { draw_id } => {
+            to_json_binary(&query_ticket_holders(deps, &_env, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetUserNumberOfTickets { draw_id, address } => to_json_binary(
+            &query_user_number_of_tickets(deps, resolve(draw_id)?, address)?,
+        ),
+        QueryMsg::GetUserWinChance { draw_id, address } => {
+            to_json_binary(&query_user_win_chance(deps, resolve(draw_id)?, address)?)
+        }
+        QueryMsg::GetTotalTicketsBurned { draw_id } => {
+            to_json_binary(&query_total_tickets_burned(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetClaims { draw_id, address } => {
+            to_json_binary(&query_claims(deps, resolve(draw_id)?, address)?)
+        }
+        QueryMsg::GetDelegatedAmount { draw_id } => {
+            to_json_binary(&query_delegated_amount(deps, &_env, resolve(draw_id)?)?)
+        }
         QueryMsg::GetContractConfig {} => to_json_binary(&query_contract_config(deps)?),
+        QueryMsg::GetFeesCollected {} => to_json_binary(&query_fees_collected(deps)?),
+        QueryMsg::GetPauseStatus {} => to_json_binary(&query_pause_status(deps)?),
+        QueryMsg::GetDrawConfig { draw_id } => {
+            to_json_binary(&query_draw_config(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetDraws { start_after, limit } => {
+            to_json_binary(&query_draws(deps, start_after, limit)?)
+        }
+        QueryMsg::GetDrawProof { draw_id } => {
+            to_json_binary(&query_draw_proof(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetRoundHistory {
+            draw_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_round_history(
+            deps,
+            resolve(draw_id)?,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetPendingWithdrawals {} => to_json_binary(&query_pending_withdrawals(deps)?),
+        QueryMsg::GetSolvency {} => {
+            to_json_binary(&query_solvency(deps, &_env, &CONFIG.load(deps.storage)?)?)
+        }
+        QueryMsg::GetEarliestDrawTime { draw_id } => {
+            to_json_binary(&query_earliest_draw_time(deps, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetAllowedTransitions { draw_id } => {
+            to_json_binary(&query_allowed_transitions(deps, &_env, resolve(draw_id)?)?)
+        }
+        QueryMsg::GetSlashingStatus { draw_id } => {
+            to_json_binary(&query_slashing_status(deps, &_env, resolve(draw_id)?)?)
+        }
         QueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
     }
 }
@@ -723,61 +2821,286 @@ fn query_contract_config(deps: Deps) -> StdResult<Config> {
     Ok(config)
 }
 
+fn query_pending_withdrawals(deps: Deps) -> StdResult<PendingWithdrawalsResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let withdrawals = PENDING_WITHDRAWALS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, withdrawal)| withdrawal))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingWithdrawalsResponse {
+        withdrawals,
+        withdrawal_timelock_seconds: config.withdrawal_timelock_seconds,
+        withdrawal_threshold: config.withdrawal_threshold,
+    })
+}
+
+// Unbonding delegations of the contract, one per validator with the entries still unbonding
+fn query_unbonding_entries(deps: Deps, env: &Env) -> StdResult<Vec<UnbondingDelegation>> {
+    let request = QueryDelegatorUnbondingDelegationsRequest {
+        delegator_addr: env.contract.address.to_string(),
+        pagination: None,
+    };
+    let response_binary = deps.querier.query_grpc(
+        "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations".to_string(),
+        Binary::from(request.encode_to_vec()),
+    )?;
+    let response = QueryDelegatorUnbondingDelegationsResponse::decode(response_binary.as_slice())
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    Ok(response.unbonding_responses)
+}
+
+fn query_solvency(deps: Deps, env: &Env, config: &Config) -> StdResult<SolvencyResponse> {
+    let delegated = deps
+        .querier
+        .query_all_delegations(env.contract.address.to_string())?
+        .into_iter()
+        .filter(|delegation| delegation.amount.denom == config.core_denom)
+        .map(|delegation| delegation.amount.amount)
+        .sum::<Uint128>();
+
+    let mut unbonding = Uint128::zero();
+    for entry in query_unbonding_entries(deps, env)?
+        .iter()
+        .flat_map(|unbonding| &unbonding.entries)
+    {
+        unbonding += entry.balance.parse::<Uint128>()?;
+    }
+
+    let liquid = deps
+        .querier
+        .query_balance(&env.contract.address, &config.core_denom)?
+        .amount;
+
+    let (principal_owed, unpaid_prizes) = total_liabilities(deps.storage)?;
+    let total_assets = delegated + unbonding + liquid;
+    let total_liabilities = principal_owed + unpaid_prizes;
+
+    Ok(SolvencyResponse {
+        delegated,
+        unbonding,
+        liquid,
+        total_assets,
+        principal_owed,
+        unpaid_prizes,
+        total_liabilities,
+        solvent: total_assets >= total_liabilities,
+    })
+}
+
+fn query_earliest_draw_time(deps: Deps, draw_id: u64) -> StdResult<EarliestDrawTimeResponse> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+
+    Ok(EarliestDrawTimeResponse {
+        sold_out_at: draw.sold_out_at,
+        earliest_draw_time: earliest_draw_time(deps.storage, &draw)?,
+        draw_deadline: draw_deadline(deps.storage, &draw)?,
+    })
+}
+
+fn query_allowed_transitions(
+    deps: Deps,
+    env: &Env,
+    draw_id: u64,
+) -> StdResult<AllowedTransitionsResponse> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let allowed_transitions = draw
+        .draw_state
+        .next_states()
+        .into_iter()
+        .filter(|state| ensure_transition(deps.storage, env, draw_id, &draw, state).is_ok())
+        .collect();
+
+    Ok(AllowedTransitionsResponse {
+        current_state: draw.draw_state,
+        allowed_transitions,
+        emergency_state_change: EMERGENCY_STATE_CHANGE.may_load(deps.storage, draw_id)?,
+    })
+}
+
+fn query_pause_status(deps: Deps) -> StdResult<PauseStatusResponse> {
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(PauseStatusResponse {
+        paused: PAUSE_FLAGS.may_load(deps.storage)?.unwrap_or_default(),
+        guardian: config.guardian.map(|guardian| guardian.to_string()),
+    })
+}
+
+fn query_fees_collected(deps: Deps) -> StdResult<FeesCollectedResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let total_fees_collected = TOTAL_FEES_COLLECTED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+
+    Ok(FeesCollectedResponse {
+        total_fees_collected,
+        fee_bps: config.fee_bps,
+        treasury: config.treasury.map(|treasury| treasury.to_string()),
+    })
+}
+
+fn query_draw_config(deps: Deps, draw_id: u64) -> StdResult<DrawConfig> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    Ok(draw)
+}
+
+fn query_draws(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<DrawsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let draws = DRAWS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(draw_id, config)| DrawInfo { draw_id, config }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DrawsResponse { draws })
+}
+
+fn query_round_history(
+    deps: Deps,
+    draw_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<RoundHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let rounds = ROUND_HISTORY
+        .prefix(draw_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, summary)| summary))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(RoundHistoryResponse { rounds })
+}
+
 fn query_accumulated_rewards_at_undelegation(
     deps: Deps,
+    draw_id: u64,
 ) -> StdResult<AccumulatedRewardsAtUndelegationResponse> {
-    let accumulated_rewards = ACCUMALTED_REWARDS_AT_UNDELEGATION.load(deps.storage)?;
+    let accumulated_rewards = ACCUMALTED_REWARDS_AT_UNDELEGATION.load(deps.storage, draw_id)?;
     Ok(AccumulatedRewardsAtUndelegationResponse {
         accumulated_rewards,
     })
 }
 
-fn query_accumulated_rewards(deps: Deps, env: &Env) -> StdResult<AccumulatedRewardsResponse> {
+fn query_accumulated_rewards(
+    deps: Deps,
+    env: &Env,
+    draw_id: u64,
+) -> StdResult<AccumulatedRewardsResponse> {
     let config = CONFIG.load(deps.storage)?;
-    let coreum_labs_validator = config.validator_address;
+
+    // Once undelegated, the draw no longer earns rewards on its validators
+    let mut pending_rewards = Uint128::zero();
+    for (validator, draw_principal) in draw_delegations(deps.storage, draw_id)? {
+        let validator_rewards = query_validator_rewards(deps, env, &config.core_denom, &validator)?;
+
+        // Other draws delegating to the same validator get their share of the rewards
+        pending_rewards += draw_share_of(
+            validator_rewards,
+            draw_principal,
+            validator_principal(deps.storage, &validator)?,
+        );
+    }
+    let withdrawn_rewards = WITHDRAWN_REWARDS
+        .may_load(deps.storage, draw_id)?
+        .unwrap_or_default();
+    let compounded_rewards = COMPOUNDED_REWARDS
+        .may_load(deps.storage, draw_id)?
+        .unwrap_or_default();
+
+    Ok(AccumulatedRewardsResponse {
+        accumulated_rewards: withdrawn_rewards + pending_rewards,
+        withdrawn_rewards,
+        pending_rewards,
+        compounded_rewards,
+    })
+}
+
+// Pending rewards of the contract delegation to a validator
+fn query_validator_rewards(
+    deps: Deps,
+    env: &Env,
+    core_denom: &str,
+    validator: &str,
+) -> StdResult<Uint128> {
     let rewards = deps
         .querier
-        .query_delegation_rewards(env.contract.address.to_string(), coreum_labs_validator)?;
-    let mut accumulated_rewards = Uint128::zero();
+        .query_delegation_rewards(env.contract.address.to_string(), validator.to_string())?;
+    let mut validator_rewards = Uint128::zero();
     for dec_coin in rewards {
-        if dec_coin.denom == config.core_denom {
-            accumulated_rewards += dec_coin
+        if dec_coin.denom == core_denom {
+            validator_rewards += dec_coin
                 .amount
                 .to_uint_floor()
                 .try_into()
                 .unwrap_or(Uint128::zero());
         }
     }
-    Ok(AccumulatedRewardsResponse {
-        accumulated_rewards,
-    })
+    Ok(validator_rewards)
 }
 
-fn query_ticket_balance(deps: Deps, account: String) -> StdResult<QueryBalanceResponse> {
-    let denom = TICKET_DENOM.load(deps.storage)?;
-    let request = QueryBalanceRequest { account, denom };
-    request.query(&deps.querier)
+// Credit the pending rewards of a validator to its draws, to be called before any message
+// withdrawing them, explicitly or as a side effect of a delegation change
+fn withdraw_validator_rewards(
+    deps: DepsMut,
+    env: &Env,
+    core_denom: &str,
+    validator: &str,
+) -> StdResult<Uint128> {
+    // Without principal tracked on the validator there is no delegation to withdraw from
+    if validator_principal(deps.storage, validator)?.is_zero() {
+        return Ok(Uint128::zero());
+    }
+    let rewards = query_validator_rewards(deps.as_ref(), env, core_denom, validator)?;
+    credit_withdrawn_rewards(deps.storage, validator, rewards)?;
+    Ok(rewards)
 }
 
-fn query_participants_primary_market(deps: Deps) -> StdResult<ParticipantsResponse> {
-    let mut participants = vec![];
-    let total_tickets_sold = TOTAL_TICKETS_SOLD.load(deps.storage)?;
-
-    // Iterate through all ticket holders
-    let all_ticket_holders: Vec<(Addr, Uint128)> = TICKET_HOLDERS_PRIMARY_MARKET
-        .range(deps.storage, None, None, Order::Ascending)
-        .collect::<StdResult<Vec<_>>>()?;
-
-    for (addr, tickets) in all_ticket_holders {
-        if !tickets.is_zero() {
-            participants.push(ParticipantInfo {
-                address: addr.to_string(),
-                tickets,
-                win_chance: calculate_win_chance(tickets, total_tickets_sold),
-            });
-        }
+// Part of the contract delegation to each validator that belongs to the draw
+fn query_draw_delegations(
+    deps: Deps,
+    env: &Env,
+    draw_id: u64,
+) -> StdResult<Vec<(String, Uint128)>> {
+    let mut delegations = vec![];
+    for (validator, draw_principal) in draw_delegations(deps.storage, draw_id)? {
+        let delegation = deps
+            .querier
+            .query_delegation(env.contract.address.to_string(), validator.clone())?;
+        let amount = match delegation {
+            Some(d) => draw_share_of(
+                d.amount.amount,
+                draw_principal,
+                validator_principal(deps.storage, &validator)?,
+            ),
+            None => Uint128::zero(),
+        };
+        delegations.push((validator, amount));
     }
+    Ok(delegations)
+}
+
+fn query_ticket_balance(
+    deps: Deps,
+    draw_id: u64,
+    account: String,
+) -> StdResult<QueryBalanceResponse> {
+    let denom = DRAWS.load(deps.storage, draw_id)?.ticket_denom;
+    let request = QueryBalanceRequest { account, denom };
+    request.query(&deps.querier)
+}
 
+fn query_participants(deps: Deps, env: &Env, draw_id: u64) -> StdResult<ParticipantsResponse> {
+    let participants = ticket_holder_infos(deps, env, draw_id)?;
     let total_participants = participants.len() as u64;
     Ok(ParticipantsResponse {
         participants,
@@ -785,46 +3108,116 @@ fn query_participants_primary_market(deps: Deps) -> StdResult<ParticipantsRespon
     })
 }
 
-fn query_winner(deps: Deps) -> StdResult<WinnerResponse> {
+fn query_winner(deps: Deps, draw_id: u64) -> StdResult<WinnerResponse> {
     let config = CONFIG.load(deps.storage)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let fee = match FEES_PAID.may_load(deps.storage, draw_id)? {
+        Some(fee) => fee,
+        None => protocol_fee(&config, draw.accumulated_rewards),
+    };
+    let rewards = draw.accumulated_rewards + draw.bonus_rewards - fee;
+
+    let winners = prize_payouts(rewards, &draw.prize_tiers_bps, &draw.winners)
+        .into_iter()
+        .zip(&draw.prize_tiers_bps)
+        .enumerate()
+        .map(|(tier, ((address, amount), share_bps))| WinnerInfo {
+            tier: tier as u32 + 1,
+            share_bps: *share_bps,
+            paid: PRIZES_PAID.has(deps.storage, (draw_id, &address)),
+            address: address.to_string(),
+            amount,
+        })
+        .collect();
 
     Ok(WinnerResponse {
-        winner: config.winner.map(|addr| addr.to_string()),
-        rewards: config.accumulated_rewards + config.bonus_rewards,
+        winners,
+        rewards,
+        fee,
     })
 }
 
-fn query_current_state(deps: Deps) -> StdResult<CurrentStateResponse> {
-    let config = CONFIG.load(deps.storage)?;
+fn query_current_state(deps: Deps, draw_id: u64) -> StdResult<CurrentStateResponse> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
 
     Ok(CurrentStateResponse {
-        state: config.draw_state,
-        undelegation_done_timestamp: config.undelegation_done_timestamp,
+        state: draw.draw_state,
+        undelegation_done_timestamp: draw.undelegation_done_timestamp,
     })
 }
 
-fn query_number_of_tickets_sold(deps: Deps) -> StdResult<TicketsSoldResponse> {
-    let config = CONFIG.load(deps.storage)?;
-    let tickets_sold = TOTAL_TICKETS_SOLD.load(deps.storage)?;
+fn query_number_of_tickets_sold(deps: Deps, draw_id: u64) -> StdResult<TicketsSoldResponse> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let tickets_sold = TOTAL_TICKETS_SOLD.load(deps.storage, draw_id)?;
 
     Ok(TicketsSoldResponse {
-        total_tickets: config.total_tickets,
+        total_tickets: draw.total_tickets,
         tickets_sold,
-        tickets_remaining: config.total_tickets - tickets_sold,
+        tickets_remaining: draw.total_tickets - tickets_sold,
     })
 }
 
-fn query_bonus_rewards(deps: Deps) -> StdResult<BonusRewardsResponse> {
+fn query_bonus_rewards(deps: Deps, draw_id: u64) -> StdResult<BonusRewardsResponse> {
     let config = CONFIG.load(deps.storage)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+
+    let mut bonus_coins = vec![];
+    if !draw.bonus_rewards.is_zero() {
+        bonus_coins.push(CosmosCoin {
+            denom: config.core_denom,
+            amount: draw.bonus_rewards,
+        });
+    }
+    for (denom, amount) in bonus_tokens(deps.storage, draw_id)? {
+        bonus_coins.push(CosmosCoin { denom, amount });
+    }
 
     Ok(BonusRewardsResponse {
-        bonus_rewards: config.bonus_rewards,
+        bonus_rewards: draw.bonus_rewards,
+        bonus_coins,
+    })
+}
+
+fn query_bonus_contributors(
+    deps: Deps,
+    draw_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<BonusContributorsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    let contributors = BONUS_CONTRIBUTORS
+        .prefix(draw_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(address, contribution)| BonusContributorInfo {
+                address: address.to_string(),
+                amount: contribution.amount,
+                memo: contribution.memo,
+                logo_uri: contribution.logo_uri,
+                tokens: contribution.tokens,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(BonusContributorsResponse { contributors })
+}
+
+fn query_bonus_token_claims(deps: Deps, address: String) -> StdResult<BonusTokenClaimsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    Ok(BonusTokenClaimsResponse {
+        tokens: bonus_token_claims(deps.storage, &address)?,
     })
 }
 
-fn query_draft_tvl(deps: Deps) -> StdResult<DraftTvlResponse> {
+fn query_draft_tvl(deps: Deps, draw_id: u64) -> StdResult<DraftTvlResponse> {
     let config = CONFIG.load(deps.storage)?;
-    let tvl = get_draft_tvl(deps.storage)?;
+    let tvl = get_draft_tvl(deps.storage, draw_id)?;
 
     Ok(DraftTvlResponse {
         tvl,
@@ -832,19 +3225,76 @@ fn query_draft_tvl(deps: Deps) -> StdResult<DraftTvlResponse> {
     })
 }
 
-fn query_ticket_holders(deps: Deps) -> StdResult<TicketHoldersResponse> {
-    let mut holders = vec![];
-    let total_tickets_sold = TOTAL_TICKETS_SOLD.load(deps.storage)?;
-    let ticket_denom = TICKET_DENOM.load(deps.storage)?;
-    let config = CONFIG.load(deps.storage)?;
-    let total_tickets = config.total_tickets.to_string().parse::<u64>().unwrap();
+// Unbonding time of the chain, the configured fallback if the staking params can't be queried
+fn query_unbonding_seconds(deps: Deps, config: &Config) -> u64 {
+    let request_binary = Binary::from(QueryStakingParamsRequest {}.encode_to_vec());
+    let unbonding_time = deps
+        .querier
+        .query_grpc(
+            "/cosmos.staking.v1beta1.Query/Params".to_string(),
+            request_binary,
+        )
+        .ok()
+        .and_then(|response| QueryStakingParamsResponse::decode(response.as_slice()).ok())
+        .and_then(|response| response.params)
+        .and_then(|params| params.unbonding_time);
+
+    match unbonding_time {
+        // Round up, the undelegation can't complete before the unbonding time is over
+        Some(duration) if duration.seconds > 0 || duration.nanos > 0 => {
+            duration.seconds as u64 + u64::from(duration.nanos > 0)
+        }
+        _ => config.fallback_unbonding_seconds,
+    }
+}
+
+fn query_ticket_holders(deps: Deps, env: &Env, draw_id: u64) -> StdResult<TicketHoldersResponse> {
+    let holders = ticket_holder_infos(deps, env, draw_id)?;
+    let total_holders = holders.len() as u64;
+    Ok(TicketHoldersResponse {
+        holders,
+        total_holders,
+    })
+}
+
+// TICKET subunits by holder
+type HolderBalances = Vec<(Addr, Uint128)>;
+
+// TICKET balances of every holder of a draw from the bank module, in subunits
+fn query_ticket_balances(deps: Deps, env: &Env, draw_id: u64) -> StdResult<Vec<(Addr, Uint128)>> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let page_size = draw.total_tickets.to_string().parse::<u64>().unwrap();
+    let mut balances = vec![];
+    let mut next_key = vec![];
+
+    // Holders of fractions of a ticket can outnumber the tickets, follow every page
+    loop {
+        let (page, key) = query_ticket_balances_page(deps, env, draw_id, next_key, page_size)?;
+        balances.extend(page);
+        if key.is_empty() {
+            break;
+        }
+        next_key = key;
+    }
+    Ok(balances)
+}
 
+// One page of TICKET balances starting at `key`, and the key of the next page (empty on the
+// last one). The contract itself is not a holder.
+fn query_ticket_balances_page(
+    deps: Deps,
+    env: &Env,
+    draw_id: u64,
+    key: Vec<u8>,
+    limit: u64,
+) -> StdResult<(HolderBalances, Vec<u8>)> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
     let request = QueryDenomOwnersRequest {
-        denom: ticket_denom.clone(),
+        denom: draw.ticket_denom,
         pagination: Some(PageRequest {
-            key: vec![],
+            key,
             offset: 0,
-            limit: total_tickets,
+            limit,
             count_total: false,
             reverse: false,
         }),
@@ -857,61 +3307,122 @@ fn query_ticket_holders(deps: Deps) -> StdResult<TicketHoldersResponse> {
     let response = QueryDenomOwnersResponse::decode(response_binary.as_slice())
         .map_err(|e| StdError::generic_err(e.to_string()))?;
 
+    let mut balances = vec![];
     for owner in response.denom_owners {
-        let tickets = owner
+        let subunits = owner
             .balance
             .unwrap_or_default()
             .amount
             .parse::<Uint128>()
-            .unwrap_or(Uint128::zero())
-            / Uint128::from(10u128).pow(TICKET_PRECISION);
-        if !tickets.is_zero() {
-            holders.push(ParticipantInfo {
-                address: owner.address,
-                tickets,
-                win_chance: calculate_win_chance(tickets, total_tickets_sold),
-            });
+            .unwrap_or(Uint128::zero());
+        if !subunits.is_zero() && owner.address != env.contract.address.as_str() {
+            balances.push((Addr::unchecked(owner.address), subunits));
         }
     }
+    let next_key = response
+        .pagination
+        .map(|page| page.next_key)
+        .unwrap_or_default();
+    Ok((balances, next_key))
+}
 
-    let total_holders = holders.len() as u64;
-    Ok(TicketHoldersResponse {
-        holders,
-        total_holders,
-    })
+// Win chances are frozen from the sell-out until the tickets can be burned, the live bank
+// balances are used before the snapshot is frozen and once refunds start
+fn holders_frozen(draw: &DrawConfig) -> bool {
+    matches!(
+        draw.draw_state,
+        DrawState::TicketsSoldOutAccumulationInProgress
+            | DrawState::WinnerSelectedUndelegationInProcess
+    )
 }
 
-fn query_user_number_of_tickets(deps: Deps, address: String) -> StdResult<UserTicketsResponse> {
-    // Query actual balance from bank module
-    let balance = query_ticket_balance(deps, address.clone())?;
-    let tickets =
-        Uint128::from_str(&balance.balance)? / Uint128::from(10u128).pow(TICKET_PRECISION);
+// Holders of a draw and their TICKET subunits, from the snapshot while it decides the draw
+fn ticket_holder_balances(deps: Deps, env: &Env, draw_id: u64) -> StdResult<Vec<(Addr, Uint128)>> {
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    if holders_frozen(&draw) {
+        if let Some(snapshot) = holder_snapshot(deps.storage, draw_id)? {
+            return Ok(snapshot);
+        }
+    }
+    query_ticket_balances(deps, env, draw_id)
+}
 
-    Ok(UserTicketsResponse { address, tickets })
+fn ticket_holder_infos(deps: Deps, env: &Env, draw_id: u64) -> StdResult<Vec<ParticipantInfo>> {
+    let unit = ticket_unit(&DRAWS.load(deps.storage, draw_id)?);
+    let holders = ticket_holder_balances(deps, env, draw_id)?;
+    let total_subunits: Uint128 = holders.iter().map(|(_, subunits)| *subunits).sum();
+
+    Ok(holders
+        .into_iter()
+        .map(|(addr, ticket_subunits)| ParticipantInfo {
+            address: addr.to_string(),
+            tickets: ticket_subunits / unit,
+            ticket_subunits,
+            win_chance: calculate_win_chance(ticket_subunits, total_subunits),
+        })
+        .collect())
 }
 
-fn query_user_win_chance(deps: Deps, address: String) -> StdResult<UserWinChanceResponse> {
+fn query_user_number_of_tickets(
+    deps: Deps,
+    draw_id: u64,
+    address: String,
+) -> StdResult<UserTicketsResponse> {
     // Query actual balance from bank module
-    let balance = query_ticket_balance(deps, address.clone())?;
-    let tickets =
-        Uint128::from_str(&balance.balance)? / Uint128::from(10u128).pow(TICKET_PRECISION);
+    let balance = query_ticket_balance(deps, draw_id, address.clone())?;
+    let ticket_subunits = Uint128::from_str(&balance.balance)?;
+    let unit = ticket_unit(&DRAWS.load(deps.storage, draw_id)?);
+
+    Ok(UserTicketsResponse {
+        address,
+        tickets: ticket_subunits / unit,
+        ticket_subunits,
+    })
+}
 
-    let total_tickets_sold = TOTAL_TICKETS_SOLD.load(deps.storage)?;
+fn query_user_win_chance(
+    deps: Deps,
+    draw_id: u64,
+    address: String,
+) -> StdResult<UserWinChanceResponse> {
+    // From sell-out the chance is the share of the holder snapshot, before that the share of
+    // the actual balance from bank module
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+    let unit = ticket_unit(&draw);
+    let snapshot_total = HOLDER_SNAPSHOT_TOTAL.may_load(deps.storage, draw_id)?;
+    let (ticket_subunits, total_subunits) = match snapshot_total {
+        Some(total) if holders_frozen(&draw) => {
+            let holder = deps.api.addr_validate(&address)?;
+            let subunits = HOLDER_SNAPSHOT
+                .may_load(deps.storage, (draw_id, &holder))?
+                .unwrap_or_default();
+            (subunits, total)
+        }
+        _ => {
+            let balance = query_ticket_balance(deps, draw_id, address.clone())?;
+            let total_tickets_sold = TOTAL_TICKETS_SOLD.load(deps.storage, draw_id)?;
+            (
+                Uint128::from_str(&balance.balance)?,
+                total_tickets_sold * unit,
+            )
+        }
+    };
 
     Ok(UserWinChanceResponse {
         address,
-        tickets,
-        win_chance: calculate_win_chance(tickets, total_tickets_sold),
+        tickets: ticket_subunits / unit,
+        ticket_subunits,
+        win_chance: calculate_win_chance(ticket_subunits, total_subunits),
     })
 }
 
-fn query_total_tickets_burned(deps: Deps) -> StdResult<TotalBurnedResponse> {
-    let total_burned = TOTAL_TICKETS_BURNED.load(deps.storage)?;
+fn query_total_tickets_burned(deps: Deps, draw_id: u64) -> StdResult<TotalBurnedResponse> {
+    let total_burned = TOTAL_TICKETS_BURNED.load(deps.storage, draw_id)?;
 
     Ok(TotalBurnedResponse { total_burned })
 }
 
-fn query_claims(deps: Deps, address: Option<String>) -> StdResult<ClaimsResponse> {
+fn query_claims(deps: Deps, draw_id: u64, address: Option<String>) -> StdResult<ClaimsResponse> {
     let mut claims = vec![];
     let mut total_claimed = Uint128::zero();
 
@@ -920,7 +3431,7 @@ fn query_claims(deps: Deps, address: Option<String>) -> StdResult<ClaimsResponse
             // Query specific address claim
             let addr = deps.api.addr_validate(&addr)?;
             let claim_amount = CLAIMS
-                .may_load(deps.storage, &addr)?
+                .may_load(deps.storage, (draw_id, &addr))?
                 .unwrap_or(Uint128::zero());
 
             if !claim_amount.is_zero() {
@@ -934,6 +3445,7 @@ fn query_claims(deps: Deps, address: Option<String>) -> StdResult<ClaimsResponse
         None => {
             // Query all claims
             let all_claims: Vec<(Addr, Uint128)> = CLAIMS
+                .prefix(draw_id)
                 .range(deps.storage, None, None, Order::Ascending)
                 .collect::<StdResult<Vec<_>>>()?;
 
@@ -953,42 +3465,206 @@ fn query_claims(deps: Deps, address: Option<String>) -> StdResult<ClaimsResponse
     })
 }
 
-fn query_delegated_amount(deps: Deps, env: &Env) -> StdResult<DelegatedAmountResponse> {
+fn query_delegated_amount(
+    deps: Deps,
+    env: &Env,
+    draw_id: u64,
+) -> StdResult<DelegatedAmountResponse> {
     let config = CONFIG.load(deps.storage)?;
-    let delegation = deps.querier.query_delegation(
-        env.contract.address.to_string(),
-        config.validator_address.clone(),
-    )?;
 
-    let amount = delegation
-        .map(|d| Coin {
-            denom: d.amount.denom.clone(),
-            amount: d.amount.amount.to_string(),
-        })
-        .unwrap_or(Coin {
+    // Only the share of each validator delegation backed by this draw's principal
+    let amount: Uint128 = query_draw_delegations(deps, env, draw_id)?
+        .into_iter()
+        .map(|(_, amount)| amount)
+        .sum();
+
+    Ok(DelegatedAmountResponse {
+        amount: Coin {
             denom: config.core_denom,
-            amount: "0".to_string(),
-        });
+            amount: amount.to_string(),
+        },
+    })
+}
+
+fn query_slashing_status(deps: Deps, env: &Env, draw_id: u64) -> StdResult<SlashingStatusResponse> {
+    let expected_principal = get_draft_tvl(deps.storage, draw_id)?;
+    let draw = DRAWS.load(deps.storage, draw_id)?;
+
+    // Live delegations while staked, then what was recorded at undelegation
+    let actual_principal = match PRINCIPAL_RECOVERED.may_load(deps.storage, draw_id)? {
+        Some(recovered) => recovered,
+        None if is_principal_delegated(&draw.draw_state) => {
+            query_draw_delegations(deps, env, draw_id)?
+                .into_iter()
+                .map(|(_, amount)| amount)
+                .sum::<Uint128>()
+                .min(expected_principal)
+        }
+        None => expected_principal,
+    };
 
-    Ok(DelegatedAmountResponse { amount })
+    let loss = expected_principal - actual_principal;
+    let loss_factor = if expected_principal.is_zero() {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(loss, expected_principal)
+    };
+
+    Ok(SlashingStatusResponse {
+        expected_principal,
+        actual_principal,
+        loss,
+        loss_factor,
+    })
+}
+
+fn query_draw_proof(deps: Deps, draw_id: u64) -> StdResult<DrawProofResponse> {
+    let commitment = RANDOMNESS_COMMITMENT.may_load(deps.storage, draw_id)?;
+    let proof = DRAW_PROOF.may_load(deps.storage, draw_id)?;
+
+    Ok(DrawProofResponse {
+        commitment: commitment.map(|c| c.commitment),
+        proof,
+    })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let ver = cw2::get_contract_version(deps.storage)?;
 
-    //set new validator address
-    if let Some(new_validator_address) = msg.new_validator_address {
-        let new_validator = new_validator_address;
+    if ver.contract != CONTRACT_NAME {
+        return Err(StdError::generic_err("Can only upgrade from same contract type").into());
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // The single draw of a contract from before draw ids becomes draw 1
+    let mut response = Response::default();
+    if LATEST_DRAW_ID.may_load(deps.storage)?.is_none() {
+        let draw_id = migrate_legacy_draw(deps.storage, &env, msg.legacy_prize_paid)?;
+        response = response.add_attribute("migrated_draw_id", draw_id.to_string());
+    }
+
+    // Draws created before keep the precision of their TICKET token, the flag applies to new draws
+    if let Some(fractional_tickets) = msg.fractional_tickets {
         CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-            config.validator_address = new_validator.to_string();
+            config.fractional_tickets = fractional_tickets;
             Ok(config)
         })?;
     }
+    Ok(response)
+}
 
-    if ver.contract != CONTRACT_NAME {
-        return Err(StdError::generic_err("Can only upgrade from same contract type").into());
+// Moves the config, counters, holders and claims of the single draw to draw 1. The old keys
+// are reused by the per draw storage with a different layout, so they are removed
+fn migrate_legacy_draw(
+    storage: &mut dyn Storage,
+    env: &Env,
+    prize_paid: Option<bool>,
+) -> Result<u64, ContractError> {
+    let legacy = LEGACY_CONFIG.load(storage)?;
+    let tickets_sold = LEGACY_TOTAL_TICKETS_SOLD.load(storage)?;
+    let tickets_burned = LEGACY_TOTAL_TICKETS_BURNED.load(storage)?;
+    let accumulated_rewards = LEGACY_ACCUMALTED_REWARDS_AT_UNDELEGATION.load(storage)?;
+
+    let config = Config {
+        core_denom: legacy.core_denom.clone(),
+        fee_bps: 0,
+        treasury: None,
+        guardian: None,
+        min_accumulation_seconds: 0,
+        fallback_unbonding_seconds: DEFAULT_UNBONDING_SECONDS,
+        withdrawal_timelock_seconds: DEFAULT_WITHDRAWAL_TIMELOCK_SECONDS,
+        withdrawal_threshold: Uint128::zero(),
+        fractional_tickets: false,
+        draw_timeout_seconds: DEFAULT_DRAW_TIMEOUT_SECONDS,
+        bonus_denoms: vec![],
+    };
+    CONFIG.save(storage, &config)?;
+    TOTAL_FEES_COLLECTED.save(storage, &Uint128::zero())?;
+
+    // Ticket sales closed at the latest now, the draw deadline runs from the migration
+    let sold_out_at =
+        (legacy.draw_state != DrawState::TicketSalesOpen).then(|| env.block.time.seconds());
+    let draw = DrawConfig {
+        ticket_symbol: legacy.ticket_symbol,
+        ticket_denom: LEGACY_TICKET_DENOM.load(storage)?,
+        validators: vec![WeightedValidator {
+            address: legacy.validator_address.clone(),
+            weight: 1,
+        }],
+        total_tickets: legacy.total_tickets,
+        ticket_price: legacy.ticket_price,
+        max_tickets_per_user: legacy.max_tickets_per_user,
+        draw_state: legacy.draw_state.clone(),
+        prize_tiers_bps: vec![BPS_DENOMINATOR],
+        winners: legacy.winner.into_iter().collect(),
+        undelegation_done_timestamp: legacy.undelegation_done_timestamp,
+        accumulated_rewards: legacy.accumulated_rewards,
+        bonus_rewards: legacy.bonus_rewards,
+        recurring: false,
+        round: 1,
+        sales_deadline: None,
+        rewards_fallback: None,
+        sales_close: None,
+        min_tickets: Uint128::zero(),
+        sold_out_at,
+        ticket_precision: TICKET_PRECISION,
+        sales_duration: None,
+        sales_close_offset: None,
+    };
+    let draw_id = initialize_draw_storage(storage, &draw)?;
+
+    TOTAL_TICKETS_SOLD.save(storage, draw_id, &tickets_sold)?;
+    TOTAL_TICKETS_BURNED.save(storage, draw_id, &tickets_burned)?;
+    ACCUMALTED_REWARDS_AT_UNDELEGATION.save(storage, draw_id, &accumulated_rewards)?;
+    LEGACY_TICKET_DENOM.remove(storage);
+    LEGACY_TOTAL_TICKETS_SOLD.remove(storage);
+    LEGACY_TOTAL_TICKETS_BURNED.remove(storage);
+    LEGACY_ACCUMALTED_REWARDS_AT_UNDELEGATION.remove(storage);
+
+    // The principal is still with the validator until the winner is selected
+    if is_principal_delegated(&legacy.draw_state) {
+        record_delegation(
+            storage,
+            draw_id,
+            &legacy.validator_address,
+            tickets_sold * legacy.ticket_price,
+        )?;
     }
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(Response::default())
+
+    // The tickets bought are the holder snapshot of the draw, frozen if it already sold out
+    let holders = LEGACY_TICKET_HOLDERS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (addr, tickets) in holders {
+        LEGACY_TICKET_HOLDERS.remove(storage, &addr);
+        record_holder_tickets(storage, draw_id, &addr, tickets * ticket_unit(&draw))?;
+    }
+    if sold_out_at.is_some() {
+        seal_holder_snapshot(storage, draw_id)?;
+    }
+    let claims = LEGACY_CLAIMS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (addr, amount) in claims {
+        LEGACY_CLAIMS.remove(storage, &addr);
+        update_claim(storage, draw_id, &addr, amount)?;
+    }
+
+    // The single draw did not record its payout, its prize was sent at the latest when the
+    // undelegation completed. Without a fee then, a paid prize can't be claimed again.
+    let prize_paid = prize_paid.unwrap_or(matches!(
+        legacy.draw_state,
+        DrawState::UndelegationCompletedTokensCanBeBurned | DrawState::DrawFinished
+    ));
+    if let Some(winner) = draw.winners.first().filter(|_| prize_paid) {
+        PRIZES_PAID.save(
+            storage,
+            (draw_id, winner),
+            &(draw.accumulated_rewards + draw.bonus_rewards),
+        )?;
+        FEES_PAID.save(storage, draw_id, &Uint128::zero())?;
+    }
+
+    Ok(draw_id)
 }
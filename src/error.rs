@@ -38,6 +38,12 @@ pub enum ContractError {
     #[error("No funds sent")]
     NoFunds {},
 
+    #[error("Unexpected denom sent: {denom:?}")]
+    UnexpectedDenom { denom: String },
+
+    #[error("Ticket subunits must be less than one ticket (max: {max:?})")]
+    InvalidTicketSubunits { max: Uint128 },
+
     #[error("Insufficient funds (required: {required:?}, provided: {provided:?})")]
     InsufficientFunds {
         required: Uint128,
@@ -65,12 +71,67 @@ pub enum ContractError {
         undelegation_timestamp: u64,
     },
 
+    #[error(
+        "Not enough liquid funds to pay out (required: {required:?}, available: {available:?})"
+    )]
+    InsufficientLiquidity {
+        required: Uint128,
+        available: Uint128,
+    },
+
+    #[error("The contract would not cover what it owes (assets: {assets:?}, liabilities: {liabilities:?})")]
+    Insolvent {
+        assets: Uint128,
+        liabilities: Uint128,
+    },
+
     #[error("No undelegation in progress")]
     NoUndelegationInProgress {},
 
+    #[error("The undelegation timestamp can only be moved later (minimum: {minimum:?})")]
+    UndelegationTimestampTooEarly { minimum: u64 },
+
     #[error("Invalid state transition (from: {from:?}, to: {to:?})")]
     InvalidStateTransition { from: String, to: String },
 
+    #[error("Accumulation period not elapsed (current timestamp: {current_timestamp:?}, earliest draw time: {earliest_draw_time:?})")]
+    AccumulationPeriodNotElapsed {
+        current_timestamp: u64,
+        earliest_draw_time: u64,
+    },
+
+    #[error("No pending withdrawal with this id")]
+    WithdrawalNotFound {},
+
+    #[error("Withdrawal timelock not expired (current timestamp: {current_timestamp:?}, executable at: {executable_at:?})")]
+    WithdrawalTimelockNotExpired {
+        current_timestamp: u64,
+        executable_at: u64,
+    },
+
+    #[error("No emergency state change scheduled")]
+    NoEmergencyStateChange {},
+
+    #[error("Emergency timelock not expired (current timestamp: {current_timestamp:?}, executable at: {executable_at:?})")]
+    EmergencyTimelockNotExpired {
+        current_timestamp: u64,
+        executable_at: u64,
+    },
+
+    #[error("An emergency state change can only leave the delegated states by cancelling the draw, and never return to them")]
+    EmergencyStateChangeStrandsPrincipal {},
+
+    #[error("A draw can only be cancelled once its sales deadline passed without selling out or its draw deadline passed without winners")]
+    CannotCancelDraw {},
+
+    #[error(
+        "The sales close must be in the future and the minimum tickets at most the total tickets"
+    )]
+    InvalidSalesClose {},
+
+    #[error("The sales deadline must be in the future")]
+    InvalidSalesDeadline {},
+
     #[error("Cannot close ticket sales until all tickets are sold")]
     CannotCloseTicketSales {},
 
@@ -104,6 +165,29 @@ pub enum ContractError {
     #[error("Failed to distribute rewards")]
     RewardsDistributionFailed {},
 
+    #[error("Invalid validator set: it must not be empty, weights must be positive and validators unique")]
+    InvalidValidatorSet {},
+
+    #[error("The draw has no delegation to move")]
+    NoDelegationToMove {},
+
+    #[error("No withdrawn rewards to compound")]
+    NothingToCompound {},
+
+    #[error(
+        "Invalid prize tiers: at most {max_tiers:?} positive tiers adding up to 10000 basis points"
+    )]
+    InvalidPrizeTiers { max_tiers: usize },
+
+    #[error("Winners must be distinct ticket holders, one per prize tier (expected: {expected:?}, provided: {provided:?})")]
+    InvalidWinners { expected: usize, provided: usize },
+
+    #[error("Fee too high (max: {max_fee_bps:?} basis points)")]
+    FeeTooHigh { max_fee_bps: u64 },
+
+    #[error("A treasury is required to collect a protocol fee")]
+    MissingTreasury {},
+
     #[error("Invalid address: {address:?}")]
     InvalidAddress { address: String },
 
@@ -122,6 +206,42 @@ pub enum ContractError {
     #[error("No winner has been selected yet")]
     NoWinnerSelected {},
 
+    #[error("Only a winner of the draw can claim a prize")]
+    NotAWinner {},
+
+    #[error("Prize already paid")]
+    PrizeAlreadyPaid {},
+
+    #[error("Bonus rewards can only be added until the prizes are paid")]
+    BonusDepositsClosed {},
+
+    #[error("Bonus rewards are not accepted in this denom: {denom:?}")]
+    BonusDenomNotAllowed { denom: String },
+
+    #[error("Too many bonus denoms (max: {max:?})")]
+    TooManyBonusDenoms { max: usize },
+
+    #[error("No bonus tokens to claim in this denom")]
+    NoBonusTokensToClaim {},
+
+    #[error("Sponsor memo or logo URI too long (max: {max_length:?} characters)")]
+    SponsorInfoTooLong { max_length: usize },
+
     #[error("No rewards to send")]
     NoRewardsToSend {},
+
+    #[error("Randomness already committed for this draw")]
+    RandomnessAlreadyCommitted {},
+
+    #[error("Randomness commitment must be a 32 byte sha256 hash")]
+    InvalidRandomnessCommitment {},
+
+    #[error("No randomness commitment found")]
+    NoRandomnessCommitment {},
+
+    #[error("Revealed secret does not match the commitment")]
+    InvalidRandomnessReveal {},
+
+    #[error("A randomness commitment exists, the winner must be drawn with reveal_winner")]
+    CommitRevealRequired {},
 }
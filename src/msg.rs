@@ -1,53 +1,287 @@
-use crate::state::{Config, DrawState};
+use crate::state::{
+    Config, DrawConfig, DrawProof, DrawState, EmergencyStateChange, Operation, PauseFlags,
+    PendingWithdrawal, RoundSummary, SalesClose, WeightedValidator,
+};
 use coreum_wasm_sdk::types::cosmos::base::v1beta1::Coin;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Coin as CosmosCoin, Decimal, HexBinary, Uint128};
 use cw_ownable::{cw_ownable_execute, cw_ownable_query};
 
 #[cw_serde]
 pub struct InstantiateMsg {
-    /// Denom of the TICKET token (will be created on contract init)
+    /// Denom of the TICKET token of the first draw (will be created on contract init)
     pub ticket_token_symbol: String,
     /// Denom of the CORE token
     pub core_denom: String,
-    /// Coreum Labs validator address for staking
-    pub validator_address: String,
+    /// Validators to stake with, every purchase is split between them by weight
+    pub validators: Vec<WeightedValidator>,
     /// Total number of tickets available for the draft
     pub total_tickets: Uint128,
     /// Price per ticket in CORE
     pub ticket_price: Uint128,
     /// Maximum number of tickets per user
     pub max_tickets_per_user: Uint128,
+    /// Start the next round automatically once the draw is finished
+    #[serde(default)]
+    pub recurring: bool,
+    /// Share of the prize of each winner in basis points, ordered by tier (defaults to a single winner)
+    #[serde(default)]
+    pub prize_tiers_bps: Vec<u64>,
+    /// Timestamp after which anyone can cancel the draw if it did not sell out
+    pub sales_deadline: Option<u64>,
+    /// Recipient of the rewards of a cancelled draw, shared between ticket holders if not set
+    pub rewards_fallback: Option<String>,
+    /// Block time or height at which ticket sales close even if the draw did not sell out
+    pub sales_close: Option<SalesClose>,
+    /// Tickets to sell by the sales close, the draw is cancelled below it
+    #[serde(default)]
+    pub min_tickets: Uint128,
+    /// Protocol fee on the staking rewards of every prize in basis points
+    #[serde(default)]
+    pub fee_bps: u64,
+    /// Recipient of the protocol fee, required when `fee_bps` is set
+    pub treasury: Option<String>,
+    /// Account allowed to pause operations besides the owner
+    pub guardian: Option<String>,
+    /// Minimum time in seconds rewards accumulate after the sell-out before a winner can be drawn
+    #[serde(default)]
+    pub min_accumulation_seconds: u64,
+    /// Unbonding time in seconds used when the staking params of the chain can't be queried,
+    /// 7 days if not set
+    pub fallback_unbonding_seconds: Option<u64>,
+    /// Delay in seconds before a queued owner withdrawal can be executed, 3 days if not set
+    pub withdrawal_timelock_seconds: Option<u64>,
+    /// Owner withdrawals above this amount are queued behind the timelock, all of them if not set
+    pub withdrawal_threshold: Option<Uint128>,
+    /// Issue the TICKET tokens of new draws with 6 decimals so that fractions of a ticket can be
+    /// held, burned and win, otherwise tickets are whole tokens (precision 0)
+    #[serde(default)]
+    pub fractional_tickets: bool,
+    /// Time in seconds after the earliest draw time past which anyone can cancel a draw
+    /// without winners, 7 days if not set
+    pub draw_timeout_seconds: Option<u64>,
 }
+
+/// Parameters of a new draw
+#[cw_serde]
+pub struct DrawParams {
+    /// Symbol of the TICKET token of the draw (a new token is issued for every draw)
+    pub ticket_token_symbol: String,
+    /// Validators to stake with, every purchase is split between them by weight
+    pub validators: Vec<WeightedValidator>,
+    /// Total number of tickets available for the draft
+    pub total_tickets: Uint128,
+    /// Price per ticket in CORE
+    pub ticket_price: Uint128,
+    /// Maximum number of tickets per user
+    pub max_tickets_per_user: Uint128,
+    /// Start the next round automatically once the draw is finished
+    #[serde(default)]
+    pub recurring: bool,
+    /// Share of the prize of each winner in basis points, ordered by tier (defaults to a single winner)
+    #[serde(default)]
+    pub prize_tiers_bps: Vec<u64>,
+    /// Timestamp after which anyone can cancel the draw if it did not sell out
+    pub sales_deadline: Option<u64>,
+    /// Recipient of the rewards of a cancelled draw, shared between ticket holders if not set
+    pub rewards_fallback: Option<String>,
+    /// Block time or height at which ticket sales close even if the draw did not sell out
+    pub sales_close: Option<SalesClose>,
+    /// Tickets to sell by the sales close, the draw is cancelled below it
+    #[serde(default)]
+    pub min_tickets: Uint128,
+}
+
+impl From<&InstantiateMsg> for DrawParams {
+    fn from(msg: &InstantiateMsg) -> Self {
+        DrawParams {
+            ticket_token_symbol: msg.ticket_token_symbol.clone(),
+            validators: msg.validators.clone(),
+            total_tickets: msg.total_tickets,
+            ticket_price: msg.ticket_price,
+            max_tickets_per_user: msg.max_tickets_per_user,
+            recurring: msg.recurring,
+            prize_tiers_bps: msg.prize_tiers_bps.clone(),
+            sales_deadline: msg.sales_deadline,
+            rewards_fallback: msg.rewards_fallback.clone(),
+            sales_close: msg.sales_close.clone(),
+            min_tickets: msg.min_tickets,
+        }
+    }
+}
+
+/// Parameters to update when starting the next round of a draw, unset fields are kept
+#[cw_serde]
+pub struct RoundParams {
+    /// Total number of tickets available for the draft
+    pub total_tickets: Option<Uint128>,
+    /// Price per ticket in CORE
+    pub ticket_price: Option<Uint128>,
+    /// Maximum number of tickets per user
+    pub max_tickets_per_user: Option<Uint128>,
+    /// Start the next round automatically once the draw is finished
+    pub recurring: Option<bool>,
+    /// Share of the prize of each winner in basis points, ordered by tier
+    pub prize_tiers_bps: Option<Vec<u64>>,
+    /// Sales deadline of the next round, later rounds keep the same time from their start
+    pub sales_deadline: Option<u64>,
+    /// Sales close of the next round, later rounds keep the same time or blocks from their start
+    pub sales_close: Option<SalesClose>,
+    /// Tickets to sell by the sales close
+    pub min_tickets: Option<Uint128>,
+}
+
+// Every draw-specific message takes an optional `draw_id`, defaulting to the latest draw
 #[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
+    /// Create a new draw with its own TICKET token (admin only)
+    CreateDraw { params: DrawParams },
+
     /// Buy one or multiple tickets
-    BuyTicket { number_of_tickets: Uint128 },
+    BuyTicket {
+        draw_id: Option<u64>,
+        number_of_tickets: Uint128,
+    },
 
-    /// Select the winner and send funds (admin only)
-    SelectWinnerAndUndelegate { winner_address: String },
+    /// Cancel a draw that did not sell out by its sales deadline and undelegate its principal
+    CancelDraw { draw_id: Option<u64> },
 
-    /// Send funds to a recipient
+    /// Close the sales of a time-boxed draw once its sales close is reached, cancelling it
+    /// below its minimum tickets
+    CloseTicketSales { draw_id: Option<u64> },
+
+    /// Select the winner and send funds (admin only)
+    SelectWinnerAndUndelegate {
+        draw_id: Option<u64>,
+        winner_address: String,
+        /// Winners of the following prize tiers, in order
+        #[serde(default)]
+        runner_up_addresses: Vec<String>,
+    },
+
+    /// Commit to sha256(secret) while ticket sales are open (admin only)
+    CommitRandomness {
+        draw_id: Option<u64>,
+        commitment: HexBinary,
+    },
+
+    /// Reveal the committed secret after sell-out to draw the winner and undelegate (admin only)
+    RevealWinner {
+        draw_id: Option<u64>,
+        secret: HexBinary,
+    },
+
+    /// Send surplus funds to a recipient, queued behind the withdrawal timelock above the
+    /// withdrawal threshold (admin only)
     SendFunds { recipient: String, amount: Uint128 },
 
-    /// Burn tickets to get the principal back
-    BurnTickets { number_of_tickets: Uint128 },
+    /// Execute a queued withdrawal once its timelock expired (admin only)
+    ExecuteWithdrawal { withdrawal_id: u64 },
 
-    /// Add extra rewards to the pool
-    AddBonusRewardToThePool { amount: Uint128 },
+    /// Cancel a queued withdrawal (admin or guardian)
+    CancelWithdrawal { withdrawal_id: u64 },
 
-    /// Manually update the draw state (admin only)
-    UpdateDrawState { new_state: DrawState },
+    /// Burn tickets to get the principal back
+    BurnTickets {
+        draw_id: Option<u64>,
+        number_of_tickets: Uint128,
+        /// Fraction of a ticket burned on top of `number_of_tickets`, in TICKET subunits
+        #[serde(default)]
+        ticket_subunits: Uint128,
+    },
+
+    /// Add extra rewards to the pool, any CORE sent above `amount` is sent back
+    AddBonusRewardToThePool {
+        draw_id: Option<u64>,
+        amount: Uint128,
+        /// Message shown with the sponsor
+        #[serde(default)]
+        memo: Option<String>,
+        /// Logo shown with the sponsor
+        #[serde(default)]
+        logo_uri: Option<String>,
+    },
+
+    /// Apply a transition of the draw lifecycle whose conditions are met (admin only)
+    UpdateDrawState {
+        draw_id: Option<u64>,
+        new_state: DrawState,
+    },
+
+    /// Schedule a change to any state that keeps the principal where it is, or to `Cancelled`,
+    /// executable once the emergency timelock expired (admin only)
+    ScheduleEmergencyStateChange {
+        draw_id: Option<u64>,
+        new_state: DrawState,
+    },
+
+    /// Execute the scheduled emergency state change (admin only)
+    ExecuteEmergencyStateChange { draw_id: Option<u64> },
+
+    /// Cancel the scheduled emergency state change (admin only)
+    CancelEmergencyStateChange { draw_id: Option<u64> },
 
     /// Manually set the undelegation timestamp (admin only)
-    SetUndelegationTimestamp { timestamp: u64 },
+    SetUndelegationTimestamp {
+        draw_id: Option<u64>,
+        timestamp: u64,
+    },
+
+    /// Send the prizes not claimed yet to the winners (admin only)
+    SendFundsToWinner { draw_id: Option<u64> },
 
-    /// Send funds to a recipient
-    SendFundsToWinner {},
+    /// Claim the prize of the caller once the undelegation is complete (winners only)
+    ClaimPrize { draw_id: Option<u64> },
+
+    /// Claim the bonus tokens of one denom returned to the caller by a cancelled draw
+    ClaimBonusTokens { denom: String },
 
     /// Transfer token admin
-    TransferTokenAdmin { new_admin: String },
+    TransferTokenAdmin {
+        draw_id: Option<u64>,
+        new_admin: String,
+    },
+
+    /// Withdraw the pending staking rewards of every validator to the contract
+    WithdrawRewards {},
+
+    /// Delegate the withdrawn rewards of a draw during the accumulation phase
+    Compound { draw_id: Option<u64> },
+
+    /// Move the delegations of a draw to another validator, all of them if `from_validator` is not set (admin only)
+    Redelegate {
+        draw_id: Option<u64>,
+        from_validator: Option<String>,
+        to_validator: String,
+    },
+
+    /// Archive a finished draw and reopen ticket sales (admin only unless the draw is recurring, updating params is admin only)
+    StartNextRound {
+        draw_id: Option<u64>,
+        params: Option<RoundParams>,
+    },
+
+    /// Pause operations (admin or guardian)
+    Pause { operations: Vec<Operation> },
+
+    /// Resume paused operations (admin only)
+    Unpause { operations: Vec<Operation> },
+
+    /// Set or remove the guardian (admin only)
+    SetGuardian { guardian: Option<String> },
+
+    /// Set the minimum accumulation period of every draw (admin only)
+    SetMinAccumulationPeriod { seconds: u64 },
+
+    /// Set the protocol fee and its treasury (admin only)
+    UpdateFeeConfig {
+        fee_bps: u64,
+        treasury: Option<String>,
+    },
+
+    /// Set the tokens other than CORE accepted as bonus rewards (admin only)
+    SetBonusDenoms { denoms: Vec<String> },
 }
 #[cw_ownable_query]
 #[cw_serde]
@@ -57,65 +291,140 @@ pub enum QueryMsg {
     #[returns(Config)]
     GetContractConfig {},
 
+    /// Get the paused operations and the guardian
+    #[returns(PauseStatusResponse)]
+    GetPauseStatus {},
+
+    /// Get the protocol fees sent to the treasury since instantiation
+    #[returns(FeesCollectedResponse)]
+    GetFeesCollected {},
+
+    /// Get the config of a draw
+    #[returns(DrawConfig)]
+    GetDrawConfig { draw_id: Option<u64> },
+
+    /// Get all draws, paginated by draw id
+    #[returns(DrawsResponse)]
+    GetDraws {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
     /// Get the balance of a specific address
     #[returns(BalanceResponse)]
-    Balance { account: String },
+    Balance {
+        draw_id: Option<u64>,
+        account: String,
+    },
 
     /// Get all participants in the lottery
     #[returns(ParticipantsResponse)]
-    GetParticipants {},
+    GetParticipants { draw_id: Option<u64> },
 
     /// Get the current winner if selected
     #[returns(WinnerResponse)]
-    GetWinner {},
+    GetWinner { draw_id: Option<u64> },
 
     /// Get the current state of the draw
     #[returns(CurrentStateResponse)]
-    GetCurrentState {},
+    GetCurrentState { draw_id: Option<u64> },
 
     /// Get the total number of tickets sold
     #[returns(TicketsSoldResponse)]
-    GetNumberOfTicketsSold {},
+    GetNumberOfTicketsSold { draw_id: Option<u64> },
 
     /// Get the bonus rewards added to the pool
     #[returns(BonusRewardsResponse)]
-    GetBonusRewards {},
+    GetBonusRewards { draw_id: Option<u64> },
+
+    /// Get the sponsors of the bonus rewards of the current round, paginated by address
+    #[returns(BonusContributorsResponse)]
+    GetBonusContributors {
+        draw_id: Option<u64>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Get the bonus tokens an address can claim
+    #[returns(BonusTokenClaimsResponse)]
+    GetBonusTokenClaims { address: String },
 
     /// Get the total accumulated rewards
     #[returns(AccumulatedRewardsResponse)]
-    GetAccumulatedRewards {},
+    GetAccumulatedRewards { draw_id: Option<u64> },
 
     /// Get the total value locked in the draft
     #[returns(DraftTvlResponse)]
-    GetDraftTvl {},
+    GetDraftTvl { draw_id: Option<u64> },
 
     /// Get all ticket holders
     #[returns(TicketHoldersResponse)]
-    GetTicketHolders {},
+    GetTicketHolders { draw_id: Option<u64> },
 
     /// Get number of tickets owned by a user
     #[returns(UserTicketsResponse)]
-    GetUserNumberOfTickets { address: String },
+    GetUserNumberOfTickets {
+        draw_id: Option<u64>,
+        address: String,
+    },
 
     /// Get a user's chance of winning
     #[returns(UserWinChanceResponse)]
-    GetUserWinChance { address: String },
+    GetUserWinChance {
+        draw_id: Option<u64>,
+        address: String,
+    },
 
     /// Get total burned tickets
     #[returns(TotalBurnedResponse)]
-    GetTotalTicketsBurned {},
+    GetTotalTicketsBurned { draw_id: Option<u64> },
 
     /// Get total claims made by users
     #[returns(ClaimsResponse)]
-    GetClaims { address: Option<String> },
+    GetClaims {
+        draw_id: Option<u64>,
+        address: Option<String>,
+    },
 
     /// Get delegated amount
     #[returns(DelegatedAmountResponse)]
-    GetDelegatedAmount {},
+    GetDelegatedAmount { draw_id: Option<u64> },
 
     /// Get accumulated rewards at undelegation
     #[returns(AccumulatedRewardsAtUndelegationResponse)]
-    GetAccumulatedRewardsAtUndelegation {},
+    GetAccumulatedRewardsAtUndelegation { draw_id: Option<u64> },
+
+    /// Get the randomness commitment and the proof of the draw once revealed
+    #[returns(DrawProofResponse)]
+    GetDrawProof { draw_id: Option<u64> },
+
+    /// Get the owner withdrawals waiting for their timelock
+    #[returns(PendingWithdrawalsResponse)]
+    GetPendingWithdrawals {},
+
+    /// Get the CORE held by the contract against what the draws owe
+    #[returns(SolvencyResponse)]
+    GetSolvency {},
+
+    /// Get the time after which the winner of the draw can be drawn
+    #[returns(EarliestDrawTimeResponse)]
+    GetEarliestDrawTime { draw_id: Option<u64> },
+
+    /// Get the states the draw can move to now and the scheduled emergency change
+    #[returns(AllowedTransitionsResponse)]
+    GetAllowedTransitions { draw_id: Option<u64> },
+
+    /// Get the principal expected back from the validators vs what was actually delegated or undelegated
+    #[returns(SlashingStatusResponse)]
+    GetSlashingStatus { draw_id: Option<u64> },
+
+    /// Get the finished rounds of a draw, paginated by round
+    #[returns(RoundHistoryResponse)]
+    GetRoundHistory {
+        draw_id: Option<u64>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 /// Migration message for contract upgrades
@@ -126,7 +435,12 @@ pub struct BalanceResponse {
 
 #[cw_serde]
 pub struct AccumulatedRewardsResponse {
+    /// Withdrawn + pending rewards of the draw
     pub accumulated_rewards: Uint128,
+    pub withdrawn_rewards: Uint128,
+    pub pending_rewards: Uint128,
+    /// Part of the withdrawn rewards delegated again, returned with the principal on undelegation
+    pub compounded_rewards: Uint128,
 }
 
 #[cw_serde]
@@ -136,7 +450,13 @@ pub struct AccumulatedRewardsAtUndelegationResponse {
 
 #[cw_serde]
 pub struct MigrateMsg {
-    pub new_validator_address: Option<String>,
+    /// Enable or disable fractional tickets for the draws created after the migration
+    #[serde(default)]
+    pub fractional_tickets: Option<bool>,
+    /// Whether the prize of the single draw of a contract from before draw ids was already sent,
+    /// by default only once its undelegation completed
+    #[serde(default)]
+    pub legacy_prize_paid: Option<bool>,
 }
 
 /// Response structures for queries
@@ -151,13 +471,37 @@ pub struct ParticipantsResponse {
 pub struct ParticipantInfo {
     pub address: String,
     pub tickets: Uint128,
+    pub ticket_subunits: Uint128,
     pub win_chance: String, // Formatted as percentage
 }
 
 #[cw_serde]
 pub struct WinnerResponse {
-    pub winner: Option<String>,
+    pub winners: Vec<WinnerInfo>,
     pub rewards: Uint128,
+    pub fee: Uint128,
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub paused: PauseFlags,
+    pub guardian: Option<String>,
+}
+
+#[cw_serde]
+pub struct FeesCollectedResponse {
+    pub total_fees_collected: Uint128,
+    pub fee_bps: u64,
+    pub treasury: Option<String>,
+}
+
+#[cw_serde]
+pub struct WinnerInfo {
+    pub address: String,
+    pub tier: u32,
+    pub share_bps: u64,
+    pub amount: Uint128,
+    pub paid: bool,
 }
 
 #[cw_serde]
@@ -175,7 +519,10 @@ pub struct TicketsSoldResponse {
 
 #[cw_serde]
 pub struct BonusRewardsResponse {
+    /// Bonus rewards in CORE
     pub bonus_rewards: Uint128,
+    /// Bonus rewards in every denom, CORE included
+    pub bonus_coins: Vec<CosmosCoin>,
 }
 
 #[cw_serde]
@@ -194,12 +541,14 @@ pub struct TicketHoldersResponse {
 pub struct UserTicketsResponse {
     pub address: String,
     pub tickets: Uint128,
+    pub ticket_subunits: Uint128,
 }
 
 #[cw_serde]
 pub struct UserWinChanceResponse {
     pub address: String,
     pub tickets: Uint128,
+    pub ticket_subunits: Uint128,
     pub win_chance: String, // Formatted as percentage
 }
 
@@ -252,3 +601,93 @@ pub struct PaginationResponse {
 pub struct DelegatedAmountResponse {
     pub amount: Coin,
 }
+
+#[cw_serde]
+pub struct DrawsResponse {
+    pub draws: Vec<DrawInfo>,
+}
+
+#[cw_serde]
+pub struct DrawInfo {
+    pub draw_id: u64,
+    pub config: DrawConfig,
+}
+
+#[cw_serde]
+pub struct DrawProofResponse {
+    pub commitment: Option<HexBinary>,
+    pub proof: Option<DrawProof>,
+}
+
+#[cw_serde]
+pub struct BonusContributorInfo {
+    pub address: String,
+    pub amount: Uint128,
+    pub memo: Option<String>,
+    pub logo_uri: Option<String>,
+    /// Bonus rewards sponsored in tokens other than CORE
+    pub tokens: Vec<CosmosCoin>,
+}
+
+#[cw_serde]
+pub struct BonusContributorsResponse {
+    pub contributors: Vec<BonusContributorInfo>,
+}
+
+#[cw_serde]
+pub struct BonusTokenClaimsResponse {
+    /// Bonus tokens returned by cancelled draws, sorted by denom
+    pub tokens: Vec<CosmosCoin>,
+}
+
+#[cw_serde]
+pub struct PendingWithdrawalsResponse {
+    pub withdrawals: Vec<PendingWithdrawal>,
+    pub withdrawal_timelock_seconds: u64,
+    pub withdrawal_threshold: Uint128,
+}
+
+#[cw_serde]
+pub struct SolvencyResponse {
+    pub delegated: Uint128,
+    pub unbonding: Uint128,
+    pub liquid: Uint128,
+    pub total_assets: Uint128,
+    /// Refunds of the tickets not burned yet
+    pub principal_owed: Uint128,
+    /// Prizes and protocol fees not paid yet
+    pub unpaid_prizes: Uint128,
+    pub total_liabilities: Uint128,
+    pub solvent: bool,
+}
+
+#[cw_serde]
+pub struct EarliestDrawTimeResponse {
+    pub sold_out_at: Option<u64>,
+    /// Not set until ticket sales are closed
+    pub earliest_draw_time: Option<u64>,
+    /// Anyone can cancel the draw from this time if no winner was drawn
+    pub draw_deadline: Option<u64>,
+}
+
+#[cw_serde]
+pub struct AllowedTransitionsResponse {
+    pub current_state: DrawState,
+    /// Transitions of the lifecycle whose conditions are currently met
+    pub allowed_transitions: Vec<DrawState>,
+    pub emergency_state_change: Option<EmergencyStateChange>,
+}
+
+#[cw_serde]
+pub struct SlashingStatusResponse {
+    pub expected_principal: Uint128,
+    pub actual_principal: Uint128,
+    pub loss: Uint128,
+    /// Share of the principal lost, refunds are scaled by `1 - loss_factor`
+    pub loss_factor: Decimal,
+}
+
+#[cw_serde]
+pub struct RoundHistoryResponse {
+    pub rounds: Vec<RoundSummary>,
+}
@@ -1,7 +1,8 @@
-use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, BlockInfo, Coin, HexBinary, Order, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub enum DrawState {
@@ -10,13 +11,277 @@ pub enum DrawState {
     WinnerSelectedUndelegationInProcess,    // Winner selected, waiting for undelegation
     UndelegationCompletedTokensCanBeBurned, // Undelegation completed, tickets can be burned
     DrawFinished,                           // All tickets burned, draw cycle complete
+    Cancelled, // Not sold out by the sales deadline or no winner drawn in time, tickets are refunded
+}
+
+impl DrawState {
+    // Normal lifecycle of a round, a finished draw is restarted with start_next_round and any
+    // other change goes through the emergency timelock
+    pub fn next_states(&self) -> Vec<DrawState> {
+        match self {
+            DrawState::TicketSalesOpen => vec![
+                DrawState::TicketsSoldOutAccumulationInProgress,
+                DrawState::Cancelled,
+            ],
+            DrawState::TicketsSoldOutAccumulationInProgress => vec![
+                DrawState::WinnerSelectedUndelegationInProcess,
+                DrawState::Cancelled,
+            ],
+            DrawState::WinnerSelectedUndelegationInProcess => {
+                vec![DrawState::UndelegationCompletedTokensCanBeBurned]
+            }
+            DrawState::UndelegationCompletedTokensCanBeBurned => vec![DrawState::DrawFinished],
+            DrawState::DrawFinished => vec![],
+            DrawState::Cancelled => vec![DrawState::DrawFinished],
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
+    pub core_denom: String, // Denom of CORE token (ucore), shared by every draw
+    #[serde(default)]
+    pub fee_bps: u64, // Protocol fee taken from the staking rewards of every prize
+    #[serde(default)]
+    pub treasury: Option<Addr>, // Recipient of the protocol fee
+    #[serde(default)]
+    pub guardian: Option<Addr>, // Can pause operations but not unpause them
+    #[serde(default)]
+    pub min_accumulation_seconds: u64, // Minimum time between the sell-out and the winner selection
+    #[serde(default = "default_unbonding_seconds")]
+    pub fallback_unbonding_seconds: u64, // Used when the staking params of the chain can't be queried
+    #[serde(default = "default_withdrawal_timelock_seconds")]
+    pub withdrawal_timelock_seconds: u64, // Delay before a queued owner withdrawal can be executed
+    #[serde(default)]
+    pub withdrawal_threshold: Uint128, // Owner withdrawals above this amount are queued
+    #[serde(default)]
+    pub fractional_tickets: bool, // TICKET tokens of new draws are divisible, otherwise precision 0
+    #[serde(default = "default_draw_timeout_seconds")]
+    pub draw_timeout_seconds: u64, // Anyone can cancel a draw without winners this long after the earliest draw time
+    #[serde(default)]
+    pub bonus_denoms: Vec<String>, // Tokens other than CORE accepted as bonus rewards, set by the owner
+}
+
+fn default_unbonding_seconds() -> u64 {
+    DEFAULT_UNBONDING_SECONDS
+}
+
+fn default_withdrawal_timelock_seconds() -> u64 {
+    DEFAULT_WITHDRAWAL_TIMELOCK_SECONDS
+}
+
+fn default_draw_timeout_seconds() -> u64 {
+    DEFAULT_DRAW_TIMEOUT_SECONDS
+}
+
+fn default_ticket_precision() -> u32 {
+    TICKET_PRECISION
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct PauseFlags {
+    pub buy: bool,        // Buying tickets
+    pub burn: bool,       // Burning tickets for a refund
+    pub payout: bool,     // Paying prizes to the winners
+    pub admin_send: bool, // Owner transfers out of the contract
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum Operation {
+    Buy,
+    Burn,
+    Payout,
+    AdminSend,
+}
+
+impl PauseFlags {
+    pub fn is_paused(&self, operation: &Operation) -> bool {
+        match operation {
+            Operation::Buy => self.buy,
+            Operation::Burn => self.burn,
+            Operation::Payout => self.payout,
+            Operation::AdminSend => self.admin_send,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum SalesClose {
+    Time(u64),   // Block time in seconds
+    Height(u64), // Block height
+}
+
+impl SalesClose {
+    pub fn is_reached(&self, block: &BlockInfo) -> bool {
+        match self {
+            SalesClose::Time(time) => block.time.seconds() >= *time,
+            SalesClose::Height(height) => block.height >= *height,
+        }
+    }
+
+    // Time or blocks left from `block` until the sales close
+    pub fn offset_from(&self, block: &BlockInfo) -> SalesClose {
+        match self {
+            SalesClose::Time(time) => SalesClose::Time(time.saturating_sub(block.time.seconds())),
+            SalesClose::Height(height) => SalesClose::Height(height.saturating_sub(block.height)),
+        }
+    }
+
+    // Sales close the same time or number of blocks after `block`
+    pub fn after(&self, block: &BlockInfo) -> SalesClose {
+        match self {
+            SalesClose::Time(seconds) => SalesClose::Time(block.time.seconds() + seconds),
+            SalesClose::Height(blocks) => SalesClose::Height(block.height + blocks),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WeightedValidator {
+    pub address: String, // Validator operator address
+    pub weight: u64,     // Relative share of every purchase staked with the validator
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DrawConfig {
+    pub ticket_symbol: String,                    // Symbol of the TICKET token
+    pub ticket_denom: String,                     // Denom of the TICKET token issued for this draw
+    pub validators: Vec<WeightedValidator>,       // Validators new purchases are staked with
+    pub total_tickets: Uint128,                   // Total number of tickets available
+    pub max_tickets_per_user: Uint128,            // Maximum number of tickets per user
+    pub ticket_price: Uint128,                    // Price per ticket in ucore
+    pub draw_state: DrawState,                    // Current state of the draw
+    pub prize_tiers_bps: Vec<u64>,                // Share of the prize of each tier in basis points
+    pub winners: Vec<Addr>, // Winners ordered by prize tier (empty until selected)
+    pub undelegation_done_timestamp: Option<u64>, // Timestamp at which undelegation will complete
+    pub accumulated_rewards: Uint128, // Total rewards accumulated
+    pub bonus_rewards: Uint128, // Additional bonus rewards
+    pub recurring: bool,    // Start the next round once the draw is finished
+    pub round: u64,         // Current round of the draw, starting at 1
+    #[serde(default)]
+    pub sales_deadline: Option<u64>, // Timestamp after which the draw can be cancelled
+    #[serde(default)]
+    pub rewards_fallback: Option<Addr>, // Recipient of the rewards if the draw is cancelled
+    #[serde(default)]
+    pub sales_close: Option<SalesClose>, // Sales close at this time or height even if not sold out
+    #[serde(default)]
+    pub min_tickets: Uint128, // Tickets a time-boxed draw needs to sell, otherwise it is cancelled
+    #[serde(default)]
+    pub sold_out_at: Option<u64>, // Timestamp at which ticket sales closed
+    #[serde(default = "default_ticket_precision")]
+    pub ticket_precision: u32, // Decimals of the TICKET token, one ticket is 10^precision subunits
+    #[serde(default)]
+    pub sales_duration: Option<u64>, // Seconds from the start of a round to its sales deadline
+    #[serde(default)]
+    pub sales_close_offset: Option<SalesClose>, // Seconds or blocks from the start of a round to its sales close
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoundSummary {
+    pub round: u64,                   // Round number within the draw
+    pub winners: Vec<Addr>,           // Winners of the round ordered by prize tier
+    pub accumulated_rewards: Uint128, // Staking rewards won
+    pub bonus_rewards: Uint128,       // Bonus rewards won
+    pub ticket_price: Uint128,        // Price per ticket in ucore
+    pub total_tickets: Uint128,       // Total number of tickets available
+    pub tickets_sold: Uint128,        // Number of tickets sold
+    pub tickets_burned: Uint128,      // Number of tickets burned
+    pub total_claimed: Uint128,       // Principal returned to ticket holders
+    pub proof: Option<DrawProof>,     // Proof of the draw if the winner was revealed
+    pub finished_at: u64,             // Timestamp at which the round was archived
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BonusContribution {
+    pub amount: Uint128,          // Bonus rewards sponsored this round
+    pub memo: Option<String>,     // Message of the sponsor, from its latest deposit
+    pub logo_uri: Option<String>, // Logo of the sponsor, from its latest deposit
+    #[serde(default)]
+    pub tokens: Vec<Coin>, // Bonus tokens sponsored this round, sorted by denom
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingWithdrawal {
+    pub id: u64,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub executable_at: u64, // Timestamp after which the withdrawal can be executed
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EmergencyStateChange {
+    pub from_state: DrawState, // State the change was scheduled from
+    pub new_state: DrawState,  // State the draw will be forced into
+    pub executable_at: u64,    // Timestamp after which the change can be executed
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RandomnessCommitment {
+    pub commitment: HexBinary,    // sha256 of the secret the owner will reveal
+    pub committed_at_height: u64, // Block height at which the commitment was made
+    #[serde(default)]
+    pub sold_out_height: Option<u64>, // Height of the block that closed ticket sales
+    #[serde(default)]
+    pub sold_out_time_nanos: Option<u64>, // Time of the block that closed ticket sales in nanoseconds
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DrawProof {
+    pub commitment: HexBinary,        // Commitment made during ticket sales
+    pub secret: HexBinary,            // Secret revealed by the owner
+    pub block_height: u64,            // Height of the block that closed ticket sales
+    pub block_time_nanos: u64,        // Time of the block that closed ticket sales in nanoseconds
+    pub seed: HexBinary,              // sha256(secret || block_height || block_time_nanos)
+    pub ticket_indexes: Vec<Uint128>, // Winning TICKET subunit of each tier, see `draw_winners`
+    pub total_tickets: Uint128, // TICKET subunits of the holder snapshot the first index was drawn from
+    pub winners: Vec<Addr>,     // Holders of the winning tickets
+}
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const TICKET_PRECISION: u32 = 6; // Decimals of divisible TICKET tokens
+pub const DEFAULT_UNBONDING_SECONDS: u64 = 7 * 24 * 60 * 60; // Unbonding time of Coreum
+pub const DEFAULT_WITHDRAWAL_TIMELOCK_SECONDS: u64 = 3 * 24 * 60 * 60;
+pub const DEFAULT_DRAW_TIMEOUT_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+// Key storage items
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const LATEST_DRAW_ID: Item<u64> = Item::new("latest_draw_id");
+pub const PAUSE_FLAGS: Item<PauseFlags> = Item::new("pause_flags");
+pub const DRAWS: Map<u64, DrawConfig> = Map::new("draws"); // Draw id -> Draw config
+pub const TOTAL_TICKETS_SOLD: Map<u64, Uint128> = Map::new("total_tickets_sold");
+pub const TOTAL_TICKETS_BURNED: Map<u64, Uint128> = Map::new("total_tickets_burned");
+pub const HOLDER_SNAPSHOT: Map<(u64, &Addr), Uint128> = Map::new("holder_snapshot"); // (Draw id, Address) -> TICKET subunits minted to the address
+pub const HOLDER_SNAPSHOT_TOTAL: Map<u64, Uint128> = Map::new("holder_snapshot_total"); // Draw id -> TICKET subunits in the holder snapshot, set at sell-out
+pub const TICKET_SUBUNITS_BURNED: Map<u64, Uint128> = Map::new("ticket_subunits_burned"); // Draw id -> Subunits burned beyond the whole tickets burned
+pub const CLAIMS: Map<(u64, &Addr), Uint128> = Map::new("claims"); // (Draw id, Address) -> Amount claimed
+
+pub const ACCUMALTED_REWARDS_AT_UNDELEGATION: Map<u64, Uint128> =
+    Map::new("accumalted_rewards_at_undelegation");
+pub const RANDOMNESS_COMMITMENT: Map<u64, RandomnessCommitment> = Map::new("randomness_commitment");
+pub const DRAW_PROOF: Map<u64, DrawProof> = Map::new("draw_proof");
+pub const EMERGENCY_STATE_CHANGE: Map<u64, EmergencyStateChange> =
+    Map::new("emergency_state_change"); // Draw id -> Scheduled emergency state change
+pub const BONUS_TOKENS: Map<(u64, &str), Uint128> = Map::new("bonus_tokens"); // (Draw id, Denom) -> Bonus rewards in a token other than CORE
+pub const BONUS_CONTRIBUTORS: Map<(u64, &Addr), BonusContribution> = Map::new("bonus_contributors"); // (Draw id, Sponsor) -> Bonus rewards sponsored this round
+pub const BONUS_TOKEN_CLAIMS: Map<(&Addr, &str), Uint128> = Map::new("bonus_token_claims"); // (Sponsor, Denom) -> Bonus tokens returned by cancelled draws, to claim
+pub const PENDING_WITHDRAWALS: Map<u64, PendingWithdrawal> = Map::new("pending_withdrawals"); // Withdrawal id -> Queued owner withdrawal
+pub const LATEST_WITHDRAWAL_ID: Item<u64> = Item::new("latest_withdrawal_id");
+pub const DRAW_DELEGATIONS: Map<(u64, &str), Uint128> = Map::new("draw_delegations"); // (Draw id, Validator) -> Principal delegated
+pub const PRINCIPAL_RECOVERED: Map<u64, Uint128> = Map::new("principal_recovered"); // Draw id -> Principal actually undelegated
+pub const PRINCIPAL_SETTLED: Map<u64, bool> = Map::new("principal_settled"); // Draw id -> Principal recovered measured again once the undelegation completed
+pub const WITHDRAWN_REWARDS: Map<u64, Uint128> = Map::new("withdrawn_rewards"); // Draw id -> Rewards withdrawn to the contract
+pub const COMPOUNDED_REWARDS: Map<u64, Uint128> = Map::new("compounded_rewards"); // Draw id -> Withdrawn rewards delegated again
+pub const ROUND_HISTORY: Map<(u64, u64), RoundSummary> = Map::new("round_history"); // (Draw id, Round) -> Round summary
+pub const PRIZES_PAID: Map<(u64, &Addr), Uint128> = Map::new("prizes_paid"); // (Draw id, Winner) -> Prize paid this round
+pub const FEES_PAID: Map<u64, Uint128> = Map::new("fees_paid"); // Draw id -> Protocol fee sent for this round
+pub const TOTAL_FEES_COLLECTED: Item<Uint128> = Item::new("total_fees_collected"); // Lifetime protocol fees sent to the treasury
+
+// Storage of the single draw contract before draws had ids, only read when migrating it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyConfig {
     pub ticket_symbol: String,                    // Denom of the TICKET token
     pub core_denom: String,                       // Denom of CORE token (ucore)
-    pub validator_address: String,                // Coreum Labs validator address
+    pub validator_address: String,                // Validator the principal is delegated to
     pub total_tickets: Uint128,                   // Total number of tickets available
     pub max_tickets_per_user: Uint128,            // Maximum number of tickets per user
     pub ticket_price: Uint128,                    // Price per ticket in ucore
@@ -27,72 +292,257 @@ pub struct Config {
     pub bonus_rewards: Uint128,                   // Additional bonus rewards
 }
 
-// Key storage items
-pub const CONFIG: Item<Config> = Item::new("config");
-pub const TICKET_HOLDERS_PRIMARY_MARKET: Map<&Addr, Uint128> = Map::new("ticket_holders"); // Address -> Number of tickets
-pub const TOTAL_TICKETS_SOLD: Item<Uint128> = Item::new("total_tickets_sold");
-pub const TOTAL_TICKETS_BURNED: Item<Uint128> = Item::new("total_tickets_burned");
-pub const CLAIMS: Map<&Addr, Uint128> = Map::new("claims"); // Address -> Amount claimed
-
-pub const TICKET_DENOM: Item<String> = Item::new("ticket_denom");
-pub const ACCUMALTED_REWARDS_AT_UNDELEGATION: Item<Uint128> =
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+pub const LEGACY_TICKET_DENOM: Item<String> = Item::new("ticket_denom");
+pub const LEGACY_TOTAL_TICKETS_SOLD: Item<Uint128> = Item::new("total_tickets_sold");
+pub const LEGACY_TOTAL_TICKETS_BURNED: Item<Uint128> = Item::new("total_tickets_burned");
+pub const LEGACY_ACCUMALTED_REWARDS_AT_UNDELEGATION: Item<Uint128> =
     Item::new("accumalted_rewards_at_undelegation");
+pub const LEGACY_TICKET_HOLDERS: Map<&Addr, Uint128> = Map::new("ticket_holders"); // Address -> Number of tickets
+pub const LEGACY_CLAIMS: Map<&Addr, Uint128> = Map::new("claims"); // Address -> Amount claimed
 
-// Initialize the storage
-pub fn initialize_storage(storage: &mut dyn Storage) -> StdResult<()> {
-    TOTAL_TICKETS_SOLD.save(storage, &Uint128::zero())?;
-    TOTAL_TICKETS_BURNED.save(storage, &Uint128::zero())?;
-    TICKET_DENOM.save(storage, &"".to_string())?;
-    ACCUMALTED_REWARDS_AT_UNDELEGATION.save(storage, &Uint128::zero())?;
-    Ok(())
+// Initialize the storage of a new draw and return its id
+pub fn initialize_draw_storage(storage: &mut dyn Storage, draw: &DrawConfig) -> StdResult<u64> {
+    let draw_id = LATEST_DRAW_ID.may_load(storage)?.unwrap_or_default() + 1;
+    DRAWS.save(storage, draw_id, draw)?;
+    TOTAL_TICKETS_SOLD.save(storage, draw_id, &Uint128::zero())?;
+    TOTAL_TICKETS_BURNED.save(storage, draw_id, &Uint128::zero())?;
+    ACCUMALTED_REWARDS_AT_UNDELEGATION.save(storage, draw_id, &Uint128::zero())?;
+    LATEST_DRAW_ID.save(storage, &draw_id)?;
+    Ok(draw_id)
 }
 
-// Helper functions to work with state
+pub fn update_draw<F>(storage: &mut dyn Storage, draw_id: u64, action: F) -> StdResult<DrawConfig>
+where
+    F: FnOnce(DrawConfig) -> StdResult<DrawConfig>,
+{
+    let draw = action(DRAWS.load(storage, draw_id)?)?;
+    DRAWS.save(storage, draw_id, &draw)?;
+    Ok(draw)
+}
+
+// Archive the finished round of a draw and reopen ticket sales for the next one
+pub fn start_next_round(
+    storage: &mut dyn Storage,
+    draw_id: u64,
+    block: &BlockInfo,
+) -> StdResult<u64> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let total_claimed = CLAIMS
+        .prefix(draw_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, amount)| amount))
+        .sum::<StdResult<Uint128>>()?;
+
+    let summary = RoundSummary {
+        round: draw.round,
+        winners: draw.winners.clone(),
+        accumulated_rewards: draw.accumulated_rewards,
+        bonus_rewards: draw.bonus_rewards,
+        ticket_price: draw.ticket_price,
+        total_tickets: draw.total_tickets,
+        tickets_sold: TOTAL_TICKETS_SOLD.load(storage, draw_id)?,
+        tickets_burned: TOTAL_TICKETS_BURNED.load(storage, draw_id)?,
+        total_claimed,
+        proof: DRAW_PROOF.may_load(storage, draw_id)?,
+        finished_at: block.time.seconds(),
+    };
+    ROUND_HISTORY.save(storage, (draw_id, draw.round), &summary)?;
+
+    // Reset the per round state, the TICKET token is reused since all tickets were burned
+    let claimers = CLAIMS
+        .prefix(draw_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for addr in claimers {
+        CLAIMS.remove(storage, (draw_id, &addr));
+    }
+    let validators = DRAW_DELEGATIONS
+        .prefix(draw_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for validator in validators {
+        DRAW_DELEGATIONS.remove(storage, (draw_id, &validator));
+    }
+    for winner in &draw.winners {
+        PRIZES_PAID.remove(storage, (draw_id, winner));
+    }
+    clear_bonus_tokens(storage, draw_id)?;
+    let sponsors = BONUS_CONTRIBUTORS
+        .prefix(draw_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for addr in sponsors {
+        BONUS_CONTRIBUTORS.remove(storage, (draw_id, &addr));
+    }
+    TOTAL_TICKETS_SOLD.save(storage, draw_id, &Uint128::zero())?;
+    TOTAL_TICKETS_BURNED.save(storage, draw_id, &Uint128::zero())?;
+    TICKET_SUBUNITS_BURNED.remove(storage, draw_id);
+    clear_holder_snapshot(storage, draw_id)?;
+    ACCUMALTED_REWARDS_AT_UNDELEGATION.save(storage, draw_id, &Uint128::zero())?;
+    RANDOMNESS_COMMITMENT.remove(storage, draw_id);
+    DRAW_PROOF.remove(storage, draw_id);
+    PRINCIPAL_RECOVERED.remove(storage, draw_id);
+    PRINCIPAL_SETTLED.remove(storage, draw_id);
+    WITHDRAWN_REWARDS.remove(storage, draw_id);
+    COMPOUNDED_REWARDS.remove(storage, draw_id);
+    FEES_PAID.remove(storage, draw_id);
+    EMERGENCY_STATE_CHANGE.remove(storage, draw_id);
 
-pub fn increment_tickets_sold(storage: &mut dyn Storage, amount: Uint128) -> StdResult<Uint128> {
-    TOTAL_TICKETS_SOLD.update(storage, |current| -> StdResult<_> { Ok(current + amount) })
+    // The sales deadline and close of the next round are as far from its start as they were
+    // from the start of the first one
+    let next_round = draw.round + 1;
+    let sales_deadline = draw
+        .sales_duration
+        .map(|duration| block.time.seconds() + duration);
+    let sales_close = draw
+        .sales_close_offset
+        .as_ref()
+        .map(|offset| offset.after(block));
+    DRAWS.save(
+        storage,
+        draw_id,
+        &DrawConfig {
+            draw_state: DrawState::TicketSalesOpen,
+            winners: vec![],
+            undelegation_done_timestamp: None,
+            accumulated_rewards: Uint128::zero(),
+            bonus_rewards: Uint128::zero(),
+            round: next_round,
+            sales_deadline,
+            sales_close,
+            sold_out_at: None,
+            ..draw
+        },
+    )?;
+    Ok(next_round)
 }
 
-pub fn increment_tickets_burned(storage: &mut dyn Storage, amount: Uint128) -> StdResult<Uint128> {
-    TOTAL_TICKETS_BURNED.update(storage, |current| -> StdResult<_> { Ok(current + amount) })
+// Nothing is paused until the flags are first set
+pub fn is_paused(storage: &dyn Storage, operation: &Operation) -> StdResult<bool> {
+    Ok(PAUSE_FLAGS
+        .may_load(storage)?
+        .unwrap_or_default()
+        .is_paused(operation))
 }
 
-pub fn update_ticket_holder_primary_market(
+// Messages and queries without a draw id target the latest draw
+pub fn resolve_draw_id(storage: &dyn Storage, draw_id: Option<u64>) -> StdResult<u64> {
+    match draw_id {
+        Some(draw_id) => Ok(draw_id),
+        None => LATEST_DRAW_ID.load(storage),
+    }
+}
+
+// Helper functions to work with state
+
+pub fn increment_tickets_sold(
     storage: &mut dyn Storage,
-    addr: &Addr,
+    draw_id: u64,
     amount: Uint128,
 ) -> StdResult<Uint128> {
-    TICKET_HOLDERS_PRIMARY_MARKET.update(storage, addr, |current| -> StdResult<_> {
-        match current {
-            Some(value) => Ok(value + amount),
-            None => Ok(amount),
-        }
+    TOTAL_TICKETS_SOLD.update(storage, draw_id, |current| -> StdResult<_> {
+        Ok(current.unwrap_or_default() + amount)
     })
 }
 
-pub fn decrease_ticket_holder_primary_market(
+pub fn increment_tickets_burned(
     storage: &mut dyn Storage,
-    addr: &Addr,
+    draw_id: u64,
     amount: Uint128,
 ) -> StdResult<Uint128> {
-    TICKET_HOLDERS_PRIMARY_MARKET.update(storage, addr, |current| -> StdResult<_> {
-        match current {
-            Some(value) => {
-                if value < amount {
-                    return Err(cosmwasm_std::StdError::generic_err("Not enough tickets"));
-                }
-                if value == amount {
-                    return Ok(Uint128::zero());
-                }
-                Ok(value - amount)
-            }
-            None => Err(cosmwasm_std::StdError::generic_err("No tickets found")),
-        }
+    TOTAL_TICKETS_BURNED.update(storage, draw_id, |current| -> StdResult<_> {
+        Ok(current.unwrap_or_default() + amount)
     })
 }
 
-pub fn update_claim(storage: &mut dyn Storage, addr: &Addr, amount: Uint128) -> StdResult<Uint128> {
-    CLAIMS.update(storage, addr, |current| -> StdResult<_> {
+// Burned fractions of a ticket add up until they make whole tickets
+pub fn increment_ticket_subunits_burned(
+    storage: &mut dyn Storage,
+    draw_id: u64,
+    subunits: Uint128,
+    unit: Uint128,
+) -> StdResult<()> {
+    let total = TICKET_SUBUNITS_BURNED
+        .may_load(storage, draw_id)?
+        .unwrap_or_default()
+        + subunits;
+    increment_tickets_burned(storage, draw_id, total / unit)?;
+    TICKET_SUBUNITS_BURNED.save(storage, draw_id, &(total % unit))
+}
+
+// Records the TICKET subunits minted to a buyer in the holder snapshot of a draw
+pub fn record_holder_tickets(
+    storage: &mut dyn Storage,
+    draw_id: u64,
+    addr: &Addr,
+    subunits: Uint128,
+) -> StdResult<()> {
+    HOLDER_SNAPSHOT.update(storage, (draw_id, addr), |current| -> StdResult<_> {
+        Ok(current.unwrap_or_default() + subunits)
+    })?;
+    Ok(())
+}
+
+// Freezes the holder snapshot of a draw at sell-out, it holds every TICKET subunit sold
+pub fn seal_holder_snapshot(storage: &mut dyn Storage, draw_id: u64) -> StdResult<()> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let total = TOTAL_TICKETS_SOLD.load(storage, draw_id)? * ticket_unit(&draw);
+    HOLDER_SNAPSHOT_TOTAL.save(storage, draw_id, &total)
+}
+
+pub fn clear_holder_snapshot(storage: &mut dyn Storage, draw_id: u64) -> StdResult<()> {
+    let holders = HOLDER_SNAPSHOT
+        .prefix(draw_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for addr in holders {
+        HOLDER_SNAPSHOT.remove(storage, (draw_id, &addr));
+    }
+    HOLDER_SNAPSHOT_TOTAL.remove(storage, draw_id);
+    Ok(())
+}
+
+// Holders of a draw at sell-out in ascending address order, None until the draw sold out
+pub fn holder_snapshot(
+    storage: &dyn Storage,
+    draw_id: u64,
+) -> StdResult<Option<Vec<(Addr, Uint128)>>> {
+    if !HOLDER_SNAPSHOT_TOTAL.has(storage, draw_id) {
+        return Ok(None);
+    }
+    HOLDER_SNAPSHOT
+        .prefix(draw_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()
+        .map(Some)
+}
+
+// Subunits of a whole TICKET token
+pub fn ticket_unit(draw: &DrawConfig) -> Uint128 {
+    Uint128::from(10u128).pow(draw.ticket_precision)
+}
+
+// Subunits of the tickets sold that were not burned yet
+pub fn ticket_subunits_left(storage: &dyn Storage, draw_id: u64) -> StdResult<Uint128> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let tickets_left = TOTAL_TICKETS_SOLD
+        .load(storage, draw_id)?
+        .saturating_sub(TOTAL_TICKETS_BURNED.load(storage, draw_id)?);
+    Ok((tickets_left * ticket_unit(&draw)).saturating_sub(
+        TICKET_SUBUNITS_BURNED
+            .may_load(storage, draw_id)?
+            .unwrap_or_default(),
+    ))
+}
+
+pub fn update_claim(
+    storage: &mut dyn Storage,
+    draw_id: u64,
+    addr: &Addr,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    CLAIMS.update(storage, (draw_id, addr), |current| -> StdResult<_> {
         match current {
             Some(value) => Ok(value + amount),
             None => Ok(amount),
@@ -100,24 +550,315 @@ pub fn update_claim(storage: &mut dyn Storage, addr: &Addr, amount: Uint128) ->
     })
 }
 
-pub fn get_draft_tvl(storage: &dyn Storage) -> StdResult<Uint128> {
-    let config = CONFIG.load(storage)?;
-    let total_sold = TOTAL_TICKETS_SOLD.load(storage)?;
-    Ok(total_sold * config.ticket_price)
+pub fn get_draft_tvl(storage: &dyn Storage, draw_id: u64) -> StdResult<Uint128> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let total_sold = TOTAL_TICKETS_SOLD.load(storage, draw_id)?;
+    Ok(total_sold * draw.ticket_price)
+}
+
+pub fn should_close_ticket_sales(storage: &dyn Storage, draw_id: u64) -> StdResult<bool> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let total_sold = TOTAL_TICKETS_SOLD.load(storage, draw_id)?;
+    Ok(total_sold == draw.total_tickets)
+}
+
+// Time-boxed draws close at their sales close, sold out or not
+pub fn sales_closed(draw: &DrawConfig, block: &BlockInfo) -> bool {
+    draw.sales_close
+        .as_ref()
+        .is_some_and(|close| close.is_reached(block))
 }
 
-pub fn should_close_ticket_sales(storage: &dyn Storage) -> StdResult<bool> {
-    let config = CONFIG.load(storage)?;
-    let total_sold = TOTAL_TICKETS_SOLD.load(storage)?;
-    Ok(total_sold == config.total_tickets)
+// A draw needs at least one ticket sold, and its minimum if one is set
+pub fn min_tickets_sold(storage: &dyn Storage, draw_id: u64) -> StdResult<bool> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let total_sold = TOTAL_TICKETS_SOLD.load(storage, draw_id)?;
+    Ok(!total_sold.is_zero() && total_sold >= draw.min_tickets)
 }
 
-pub fn all_tickets_burned(storage: &dyn Storage) -> StdResult<bool> {
-    let total_sold = TOTAL_TICKETS_SOLD.load(storage)?;
-    let total_burned = TOTAL_TICKETS_BURNED.load(storage)?;
+pub fn all_tickets_burned(storage: &dyn Storage, draw_id: u64) -> StdResult<bool> {
+    let total_sold = TOTAL_TICKETS_SOLD.load(storage, draw_id)?;
+    let total_burned = TOTAL_TICKETS_BURNED.load(storage, draw_id)?;
     Ok(total_sold == total_burned)
 }
 
+// The principal of a draw stays delegated until its winner is selected
+pub fn is_principal_delegated(draw_state: &DrawState) -> bool {
+    matches!(
+        draw_state,
+        DrawState::TicketSalesOpen | DrawState::TicketsSoldOutAccumulationInProgress
+    )
+}
+
+// Share of the rewards of a cancelled draw refunded with the tickets, nothing if the rewards
+// went to the fallback recipient
+pub fn cancelled_rewards_share(
+    storage: &dyn Storage,
+    draw_id: u64,
+    ticket_subunits: Uint128,
+) -> StdResult<Uint128> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let total_sold = TOTAL_TICKETS_SOLD.load(storage, draw_id)?;
+    if draw.draw_state != DrawState::Cancelled
+        || draw.rewards_fallback.is_some()
+        || total_sold.is_zero()
+    {
+        return Ok(Uint128::zero());
+    }
+    Ok((draw.accumulated_rewards + draw.bonus_rewards)
+        .multiply_ratio(ticket_subunits, total_sold * ticket_unit(&draw)))
+}
+
+// A slashed validator returns less than the principal on undelegation, the loss is
+// shared pro-rata by scaling every refund of the draw
+pub fn scale_refund(storage: &dyn Storage, draw_id: u64, refund: Uint128) -> StdResult<Uint128> {
+    let expected = get_draft_tvl(storage, draw_id)?;
+    match PRINCIPAL_RECOVERED.may_load(storage, draw_id)? {
+        Some(recovered) if recovered < expected => Ok(refund.multiply_ratio(recovered, expected)),
+        _ => Ok(refund),
+    }
+}
+
+// What a draw owes: the refunds of the tickets not burned yet, less any slashing, and the
+// prizes and protocol fee not paid yet. The staking rewards of a cancelled draw are refunded
+// with the tickets or went to the fallback recipient, its bonus went back to the sponsors. While the principal is delegated the prize is
+// what the draw withdrew so far, compounded rewards included as they are delegated again.
+pub fn draw_liabilities(storage: &dyn Storage, draw_id: u64) -> StdResult<(Uint128, Uint128)> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let subunits_left = ticket_subunits_left(storage, draw_id)?;
+    let principal_owed = scale_refund(
+        storage,
+        draw_id,
+        draw.ticket_price
+            .multiply_ratio(subunits_left, ticket_unit(&draw)),
+    )? + cancelled_rewards_share(storage, draw_id, subunits_left)?;
+
+    let cancelled = draw.draw_state == DrawState::Cancelled
+        || (draw.winners.is_empty() && !is_principal_delegated(&draw.draw_state));
+    if cancelled {
+        return Ok((principal_owed, Uint128::zero()));
+    }
+    let fee_paid = FEES_PAID.may_load(storage, draw_id)?.unwrap_or_default();
+    let mut prizes_paid = Uint128::zero();
+    for winner in &draw.winners {
+        prizes_paid += PRIZES_PAID
+            .may_load(storage, (draw_id, winner))?
+            .unwrap_or_default();
+    }
+    let rewards = if is_principal_delegated(&draw.draw_state) {
+        WITHDRAWN_REWARDS
+            .may_load(storage, draw_id)?
+            .unwrap_or_default()
+    } else {
+        draw.accumulated_rewards
+    };
+    let unpaid_prizes = (rewards + draw.bonus_rewards).saturating_sub(fee_paid + prizes_paid);
+
+    Ok((principal_owed, unpaid_prizes))
+}
+
+// The part of what a draw owes that must already be in the contract balance: the withdrawn
+// rewards not delegated again, and everything once its undelegation is complete
+pub fn draw_liquid_liabilities(
+    storage: &dyn Storage,
+    draw_id: u64,
+    now: u64,
+) -> StdResult<Uint128> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    let (principal_owed, unpaid_prizes) = draw_liabilities(storage, draw_id)?;
+    let undelegated = !is_principal_delegated(&draw.draw_state)
+        && draw
+            .undelegation_done_timestamp
+            .is_none_or(|timestamp| timestamp <= now);
+    if undelegated {
+        return Ok(principal_owed + unpaid_prizes);
+    }
+    let compounded = COMPOUNDED_REWARDS
+        .may_load(storage, draw_id)?
+        .unwrap_or_default();
+    Ok(unpaid_prizes.saturating_sub(compounded))
+}
+
+// What all the draws owe from the contract balance
+pub fn liquid_liabilities(storage: &dyn Storage, now: u64) -> StdResult<Uint128> {
+    let draw_ids = DRAWS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    let mut total = Uint128::zero();
+    for draw_id in draw_ids {
+        total += draw_liquid_liabilities(storage, draw_id, now)?;
+    }
+    Ok(total)
+}
+
+// Bonus rewards of a draw in tokens other than CORE, sorted by denom
+pub fn bonus_tokens(storage: &dyn Storage, draw_id: u64) -> StdResult<Vec<(String, Uint128)>> {
+    BONUS_TOKENS
+        .prefix(draw_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Bonus tokens returned on cancellation are not pushed, the sponsor claims them one denom at a
+// time so a token that can't be sent does not block the CORE refunds
+pub fn credit_bonus_tokens(
+    storage: &mut dyn Storage,
+    recipient: &Addr,
+    tokens: &[Coin],
+) -> StdResult<()> {
+    for token in tokens.iter().filter(|token| !token.amount.is_zero()) {
+        BONUS_TOKEN_CLAIMS.update(
+            storage,
+            (recipient, &token.denom),
+            |claim| -> StdResult<_> { Ok(claim.unwrap_or_default() + token.amount) },
+        )?;
+    }
+    Ok(())
+}
+
+// Bonus tokens an address can claim, sorted by denom
+pub fn bonus_token_claims(storage: &dyn Storage, recipient: &Addr) -> StdResult<Vec<Coin>> {
+    BONUS_TOKEN_CLAIMS
+        .prefix(recipient)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect()
+}
+
+pub fn clear_bonus_tokens(storage: &mut dyn Storage, draw_id: u64) -> StdResult<()> {
+    for (denom, _) in bonus_tokens(storage, draw_id)? {
+        BONUS_TOKENS.remove(storage, (draw_id, &denom));
+    }
+    Ok(())
+}
+
+// CORE reserved by the owner withdrawals waiting for their timelock
+pub fn pending_withdrawals_total(storage: &dyn Storage) -> StdResult<Uint128> {
+    PENDING_WITHDRAWALS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, withdrawal)| withdrawal.amount))
+        .sum()
+}
+
+// What all the draws owe together
+pub fn total_liabilities(storage: &dyn Storage) -> StdResult<(Uint128, Uint128)> {
+    let draw_ids = DRAWS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    let mut total = (Uint128::zero(), Uint128::zero());
+    for draw_id in draw_ids {
+        let (principal_owed, unpaid_prizes) = draw_liabilities(storage, draw_id)?;
+        total.0 += principal_owed;
+        total.1 += unpaid_prizes;
+    }
+    Ok(total)
+}
+
+// Split an amount between validators according to their weights, the rounding
+// remainder goes to the first validator
+pub fn split_by_weight(
+    amount: Uint128,
+    validators: &[WeightedValidator],
+) -> Vec<(String, Uint128)> {
+    let total_weight: u64 = validators.iter().map(|v| v.weight).sum();
+    let mut shares: Vec<(String, Uint128)> = validators
+        .iter()
+        .map(|v| {
+            (
+                v.address.clone(),
+                amount.multiply_ratio(v.weight, total_weight),
+            )
+        })
+        .collect();
+    let distributed: Uint128 = shares.iter().map(|(_, share)| *share).sum();
+    if let Some((_, first)) = shares.first_mut() {
+        *first += amount - distributed;
+    }
+    shares.retain(|(_, share)| !share.is_zero());
+    shares
+}
+
+pub fn record_delegation(
+    storage: &mut dyn Storage,
+    draw_id: u64,
+    validator: &str,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    DRAW_DELEGATIONS.update(storage, (draw_id, validator), |current| -> StdResult<_> {
+        Ok(current.unwrap_or_default() + amount)
+    })
+}
+
+// Principal of a draw still delegated, per validator
+pub fn draw_delegations(storage: &dyn Storage, draw_id: u64) -> StdResult<Vec<(String, Uint128)>> {
+    let draw = DRAWS.load(storage, draw_id)?;
+    if !is_principal_delegated(&draw.draw_state) {
+        return Ok(vec![]);
+    }
+    DRAW_DELEGATIONS
+        .prefix(draw_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Principal delegated to a validator by all the draws staking with it
+pub fn validator_principal(storage: &dyn Storage, validator: &str) -> StdResult<Uint128> {
+    let mut total = Uint128::zero();
+    for item in DRAWS.range(storage, None, None, Order::Ascending) {
+        let (draw_id, draw) = item?;
+        if is_principal_delegated(&draw.draw_state) {
+            total += DRAW_DELEGATIONS
+                .may_load(storage, (draw_id, validator))?
+                .unwrap_or_default();
+        }
+    }
+    Ok(total)
+}
+
+// Rewards withdrawn from a validator belong to the draws delegating to it, pro-rata
+pub fn credit_withdrawn_rewards(
+    storage: &mut dyn Storage,
+    validator: &str,
+    rewards: Uint128,
+) -> StdResult<()> {
+    let total_principal = validator_principal(storage, validator)?;
+    if rewards.is_zero() || total_principal.is_zero() {
+        return Ok(());
+    }
+
+    let draw_ids = DRAWS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for draw_id in draw_ids {
+        let draw_principal = draw_delegations(storage, draw_id)?
+            .into_iter()
+            .find(|(v, _)| v == validator)
+            .map(|(_, principal)| principal)
+            .unwrap_or_default();
+        if draw_principal.is_zero() {
+            continue;
+        }
+        let share = draw_share_of(rewards, draw_principal, total_principal);
+        WITHDRAWN_REWARDS.update(storage, draw_id, |current| -> StdResult<_> {
+            Ok(current.unwrap_or_default() + share)
+        })?;
+    }
+    Ok(())
+}
+
+// Draws can share a validator, so rewards and delegations on a validator are split
+// between them pro-rata to the principal each draw has delegated
+pub fn draw_share_of(
+    amount: Uint128,
+    draw_principal: Uint128,
+    total_principal: Uint128,
+) -> Uint128 {
+    if total_principal.is_zero() || draw_principal >= total_principal {
+        return amount;
+    }
+    amount.multiply_ratio(draw_principal, total_principal)
+}
+
 pub fn calculate_win_chance(user_tickets: Uint128, total_tickets_sold: Uint128) -> String {
     if total_tickets_sold.is_zero() || user_tickets.is_zero() {
         return "0.00%".to_string();
@@ -126,3 +867,119 @@ pub fn calculate_win_chance(user_tickets: Uint128, total_tickets_sold: Uint128)
     let win_chance = user_tickets.u128() as f64 / total_tickets_sold.u128() as f64 * 100.0;
     format!("{:.2}%", win_chance)
 }
+
+pub fn hash_secret(secret: &[u8]) -> HexBinary {
+    HexBinary::from(Sha256::digest(secret).to_vec())
+}
+
+// The seed mixes the revealed secret with the block that closed ticket sales. The owner
+// committed before that block and can't pick it, and revealing later doesn't change the outcome
+pub fn derive_draw_seed(secret: &[u8], block_height: u64, block_time_nanos: u64) -> HexBinary {
+    let mut hasher = Sha256::new();
+    hasher.update(secret);
+    hasher.update(block_height.to_be_bytes());
+    hasher.update(block_time_nanos.to_be_bytes());
+    HexBinary::from(hasher.finalize().to_vec())
+}
+
+pub fn ticket_index_from_seed(seed: &HexBinary, total_tickets: Uint128) -> Uint128 {
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&seed.as_slice()[..16]);
+    Uint128::from(u128::from_be_bytes(bytes) % total_tickets.u128())
+}
+
+// Every tier after the first draws from its own seed
+pub fn derive_tier_seed(seed: &HexBinary, tier: u32) -> HexBinary {
+    if tier == 0 {
+        return seed.clone();
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(seed.as_slice());
+    hasher.update(tier.to_be_bytes());
+    HexBinary::from(hasher.finalize().to_vec())
+}
+
+// Walks the holder snapshot in ascending address order, each holder owning a contiguous
+// range of TICKET subunit indexes, and returns the holder of `ticket_index`.
+// Holders in `excluded` are skipped, their tickets do not take part in the draw
+pub fn find_ticket_owner(
+    storage: &dyn Storage,
+    draw_id: u64,
+    ticket_index: Uint128,
+    excluded: &[Addr],
+) -> StdResult<Option<Addr>> {
+    let mut upper_bound = Uint128::zero();
+    for item in HOLDER_SNAPSHOT
+        .prefix(draw_id)
+        .range(storage, None, None, Order::Ascending)
+    {
+        let (addr, tickets) = item?;
+        if excluded.contains(&addr) {
+            continue;
+        }
+        upper_bound += tickets;
+        if ticket_index < upper_bound {
+            return Ok(Some(addr));
+        }
+    }
+    Ok(None)
+}
+
+// Draws one winner per prize tier without replacement: the tickets of the previous winners
+// are removed before the next index is drawn from the tier seed. Stops early when every
+// holder has won
+pub fn draw_winners(
+    storage: &dyn Storage,
+    draw_id: u64,
+    seed: &HexBinary,
+    total_tickets: Uint128,
+    tiers: usize,
+) -> StdResult<(Vec<Uint128>, Vec<Addr>)> {
+    let mut ticket_indexes = vec![];
+    let mut winners: Vec<Addr> = vec![];
+    let mut remaining_tickets = total_tickets;
+
+    for tier in 0..tiers {
+        if remaining_tickets.is_zero() {
+            break;
+        }
+        let tier_seed = derive_tier_seed(seed, tier as u32);
+        let ticket_index = ticket_index_from_seed(&tier_seed, remaining_tickets);
+        let Some(winner) = find_ticket_owner(storage, draw_id, ticket_index, &winners)? else {
+            break;
+        };
+
+        remaining_tickets =
+            remaining_tickets.saturating_sub(HOLDER_SNAPSHOT.load(storage, (draw_id, &winner))?);
+        ticket_indexes.push(ticket_index);
+        winners.push(winner);
+    }
+    Ok((ticket_indexes, winners))
+}
+
+// Protocol fee on the staking rewards of a prize, bonus rewards are paid out in full
+pub fn protocol_fee(config: &Config, accumulated_rewards: Uint128) -> Uint128 {
+    if config.treasury.is_none() {
+        return Uint128::zero();
+    }
+    accumulated_rewards.multiply_ratio(config.fee_bps, BPS_DENOMINATOR)
+}
+
+// Prize of each winner, the rounding remainder and the share of tiers left without a
+// winner go to the first tier
+pub fn prize_payouts(
+    total: Uint128,
+    prize_tiers_bps: &[u64],
+    winners: &[Addr],
+) -> Vec<(Addr, Uint128)> {
+    let mut payouts: Vec<(Addr, Uint128)> = winners
+        .iter()
+        .zip(prize_tiers_bps)
+        .map(|(winner, bps)| (winner.clone(), total.multiply_ratio(*bps, BPS_DENOMINATOR)))
+        .collect();
+    let distributed: Uint128 = payouts.iter().map(|(_, amount)| *amount).sum();
+    if let Some((_, first)) = payouts.first_mut() {
+        *first += total - distributed;
+    }
+    payouts
+}
@@ -3,27 +3,28 @@ mod tests {
     use crate::{
         error::ContractError,
         msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-        state::DrawState,
+        state::{DrawState, Operation, WeightedValidator},
     };
     use coreum_test_tube::{
         Account, AssetFT, Bank, CoreumTestApp, Module, SigningAccount, Staking, Wasm,
     };
     use coreum_wasm_sdk::types::cosmos::bank::v1beta1::{MsgSend, QueryBalanceRequest};
     use coreum_wasm_sdk::types::cosmos::staking::v1beta1::{
-        CommissionRates, Description, MsgCreateValidator,
+        CommissionRates, Description, MsgCreateValidator, QueryDelegationRequest,
+        QueryParamsRequest as StakingParamsRequest,
     };
 
     use bech32::{Bech32, Hrp};
 
     use coreum_wasm_sdk::types::coreum::asset::ft::v1::{
-        MsgMint, QueryBalanceRequest as FtQueryBalanceRequest,
+        MsgIssue, MsgMint, QueryBalanceRequest as FtQueryBalanceRequest,
         QueryBalanceResponse as FtQueryBalanceResponse,
     };
 
     use coreum_wasm_sdk::shim::Any;
     use coreum_wasm_sdk::types::cosmos::base::v1beta1::Coin as BaseCoin;
     use cosmrs::proto;
-    use cosmwasm_std::{coin, Coin as CosmoCoin, Uint128};
+    use cosmwasm_std::{coin, Coin as CosmoCoin, HexBinary, Uint128};
     use prost::Message;
     use ring::{
         rand,
@@ -112,10 +113,28 @@ mod tests {
             &InstantiateMsg {
                 ticket_token_symbol: TICKET_TOKEN.to_string(),
                 core_denom: FEE_DENOM.to_string(),
-                validator_address,
+                validators: vec![WeightedValidator {
+                    address: validator_address,
+                    weight: 1,
+                }],
                 total_tickets,
                 ticket_price,
                 max_tickets_per_user,
+                recurring: false,
+                prize_tiers_bps: vec![],
+                sales_deadline: None,
+                rewards_fallback: None,
+                sales_close: None,
+                min_tickets: Uint128::zero(),
+                fee_bps: 0,
+                treasury: None,
+                guardian: None,
+                min_accumulation_seconds: 0,
+                fallback_unbonding_seconds: None,
+                withdrawal_timelock_seconds: None,
+                withdrawal_threshold: None,
+                fractional_tickets: true,
+                draw_timeout_seconds: None,
             },
             None,
             "coreum-fun".into(),
@@ -176,7 +195,10 @@ mod tests {
 
         // Query current state
         let current_state: crate::msg::CurrentStateResponse = wasm
-            .query(&contract_address, &QueryMsg::GetCurrentState {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
             .unwrap();
 
         assert_eq!(current_state.state, DrawState::TicketSalesOpen);
@@ -218,7 +240,10 @@ mod tests {
 
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user,
         )
@@ -229,6 +254,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user.address(),
                 },
             )
@@ -238,7 +264,10 @@ mod tests {
 
         // Query total tickets sold
         let tickets_sold: crate::msg::TicketsSoldResponse = wasm
-            .query(&contract_address, &QueryMsg::GetNumberOfTicketsSold {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetNumberOfTicketsSold { draw_id: None },
+            )
             .unwrap();
 
         assert_eq!(tickets_sold.tickets_sold, number_of_tickets);
@@ -281,7 +310,10 @@ mod tests {
         // let's sell the total number_of_tickets of tickets
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user,
         )
@@ -292,6 +324,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::Balance {
+                    draw_id: None,
                     account: contract_address.clone(),
                 },
             )
@@ -303,7 +336,10 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
                 amount: bonus_amount,
+                memo: None,
+                logo_uri: None,
             },
             &[coin(bonus_amount.u128(), FEE_DENOM)],
             &admin,
@@ -312,13 +348,13 @@ mod tests {
 
         //query the current draw state: should be TicketsSoldOutAccumulationInProgress because we have sold the total number_of_tickets of tickets
         // let current_draw_state: crate::msg::CurrentStateResponse = wasm
-        //     .query(&contract_address, &QueryMsg::GetCurrentState {})
+        //     .query(&contract_address, &QueryMsg::GetCurrentState { draw_id: None })
         //     .unwrap();
         // assert_eq!(current_draw_state.state, DrawState::TicketSalesOpen);
 
         // wasm.execute(
         //     &contract_address,
-        //     &ExecuteMsg::BuyTicket { number_of_tickets },
+        //     &ExecuteMsg::BuyTicket { draw_id: None, number_of_tickets },
         //     &[coin(payment.u128(), FEE_DENOM)],
         //     &user,
         // )
@@ -329,6 +365,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user.address(),
                 },
             )
@@ -337,7 +374,10 @@ mod tests {
 
         // Check the delegate amount before undelegation
         let contract_delegated_tokens: crate::msg::DelegatedAmountResponse = wasm
-            .query(&contract_address, &QueryMsg::GetDelegatedAmount {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
             .unwrap();
         println!(
             "contract_delegated_tokens before undelegation: {:?}",
@@ -348,7 +388,9 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
                 winner_address: user.address(),
+                runner_up_addresses: vec![],
             },
             &[],
             &admin,
@@ -363,7 +405,10 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
                 amount: bonus_amount,
+                memo: None,
+                logo_uri: None,
             },
             &[coin(bonus_amount.u128(), FEE_DENOM)],
             &admin,
@@ -372,12 +417,18 @@ mod tests {
 
         // Check contract state and balances before sending funds
         let state: crate::msg::CurrentStateResponse = wasm
-            .query(&contract_address, &QueryMsg::GetCurrentState {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
             .unwrap();
         println!("Contract state before sending funds: {:?}", state.state);
 
         let delegated: crate::msg::DelegatedAmountResponse = wasm
-            .query(&contract_address, &QueryMsg::GetDelegatedAmount {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
             .unwrap();
         println!("Delegated amount before sending funds: {:?}", delegated);
 
@@ -396,7 +447,7 @@ mod tests {
         // Send funds to winner
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::SendFundsToWinner {},
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
             &[],
             &admin,
         )
@@ -404,10 +455,11 @@ mod tests {
 
         // Query winner
         let winner: crate::msg::WinnerResponse = wasm
-            .query(&contract_address, &QueryMsg::GetWinner {})
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: None })
             .unwrap();
 
-        assert_eq!(winner.winner, Some(user.address()));
+        assert_eq!(winner.winners.len(), 1);
+        assert_eq!(winner.winners[0].address, user.address());
 
         //here we need to advance the block time to let the app know that the accumulation period is over.
         //otherwise, we will get an error: Undelegation period not completed (current block: 10, undelegation block: 302410)
@@ -415,7 +467,10 @@ mod tests {
 
         //check the contract delegated tokens: should be equal to the number of tickets sold * ticket price
         let contract_delegated_tokens: crate::msg::DelegatedAmountResponse = wasm
-            .query(&contract_address, &QueryMsg::GetDelegatedAmount {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
             .unwrap();
         println!("contract_delegated_tokens: {:?}", contract_delegated_tokens);
 
@@ -431,7 +486,10 @@ mod tests {
 
         //check the contract delegated tokens: should be 0 ucore now
         let contract_delegated_tokens: crate::msg::DelegatedAmountResponse = wasm
-            .query(&contract_address, &QueryMsg::GetDelegatedAmount {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
             .unwrap();
         println!("contract_delegated_tokens: {:?}", contract_delegated_tokens);
 
@@ -444,7 +502,11 @@ mod tests {
 
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BurnTickets { number_of_tickets },
+            &ExecuteMsg::BurnTickets {
+                draw_id: None,
+                number_of_tickets,
+                ticket_subunits: Uint128::zero(),
+            },
             &[tickets_to_burn],
             &user,
         )
@@ -452,7 +514,10 @@ mod tests {
 
         // Query total tickets burned
         let total_burned: crate::msg::TotalBurnedResponse = wasm
-            .query(&contract_address, &QueryMsg::GetTotalTicketsBurned {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetTotalTicketsBurned { draw_id: None },
+            )
             .unwrap();
 
         assert_eq!(total_burned.total_burned, number_of_tickets);
@@ -506,6 +571,7 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::BuyTicket {
+                draw_id: None,
                 number_of_tickets: Uint128::from(5u128),
             },
             &[coin(1000u128, FEE_DENOM)], // insufficient funds
@@ -517,7 +583,9 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
                 winner_address: user.address(),
+                runner_up_addresses: vec![],
             },
             &[],
             &user, // not the owner
@@ -528,7 +596,9 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::BurnTickets {
+                draw_id: None,
                 number_of_tickets: Uint128::from(5u128),
+                ticket_subunits: Uint128::zero(),
             },
             &[],
             &user,
@@ -566,7 +636,10 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
                 amount: bonus_amount,
+                memo: None,
+                logo_uri: None,
             },
             &[coin(bonus_amount.u128(), FEE_DENOM)],
             &admin,
@@ -575,7 +648,10 @@ mod tests {
 
         // Verify bonus rewards were added
         let bonus_rewards: crate::msg::BonusRewardsResponse = wasm
-            .query(&contract_address, &QueryMsg::GetBonusRewards {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetBonusRewards { draw_id: None },
+            )
             .unwrap();
         assert_eq!(bonus_rewards.bonus_rewards, bonus_amount);
     }
@@ -609,7 +685,9 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::BurnTickets {
+                draw_id: None,
                 number_of_tickets: Uint128::from(5u128),
+                ticket_subunits: Uint128::zero(),
             },
             &[],
             &user,
@@ -621,7 +699,10 @@ mod tests {
         let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user,
         )
@@ -631,7 +712,9 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::BurnTickets {
+                draw_id: None,
                 number_of_tickets: Uint128::from(20u128),
+                ticket_subunits: Uint128::zero(),
             },
             &[],
             &user,
@@ -669,7 +752,10 @@ mod tests {
         let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user,
         )
@@ -677,26 +763,145 @@ mod tests {
 
         // Verify state changed
         let state: crate::msg::CurrentStateResponse = wasm
-            .query(&contract_address, &QueryMsg::GetCurrentState {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
             .unwrap();
         assert_eq!(state.state, DrawState::TicketsSoldOutAccumulationInProgress);
 
-        //Manually set the state to DrawFinished
+        // Only the winner selection can follow the sell-out
+        let transitions: crate::msg::AllowedTransitionsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetAllowedTransitions { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(
+            transitions.current_state,
+            DrawState::TicketsSoldOutAccumulationInProgress
+        );
+        assert_eq!(
+            transitions.allowed_transitions,
+            vec![DrawState::WinnerSelectedUndelegationInProcess]
+        );
+        assert_eq!(transitions.emergency_state_change, None);
+
+        // Jumping to another state is rejected
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::UpdateDrawState {
+                    draw_id: None,
+                    new_state: DrawState::DrawFinished,
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid state transition"));
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::UpdateDrawState {
+                    draw_id: None,
+                    new_state: DrawState::WinnerSelectedUndelegationInProcess,
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Use select_winner function to set winner"));
+
+        // Abnormal transitions go through the emergency timelock, the delegated principal can
+        // only be left by cancelling the draw
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ScheduleEmergencyStateChange {
+                    draw_id: None,
+                    new_state: DrawState::DrawFinished,
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("An emergency state change can only leave the delegated states"));
+
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::UpdateDrawState {
-                new_state: DrawState::DrawFinished,
+            &ExecuteMsg::ScheduleEmergencyStateChange {
+                draw_id: None,
+                new_state: DrawState::Cancelled,
             },
             &[],
             &admin,
         )
         .unwrap();
 
-        // Verify state changed
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ExecuteEmergencyStateChange { draw_id: None },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Emergency timelock not expired"));
+
+        let transitions: crate::msg::AllowedTransitionsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetAllowedTransitions { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(
+            transitions.emergency_state_change.unwrap().new_state,
+            DrawState::Cancelled
+        );
+
+        app.increase_time(SECONDS_PER_DAY * 3);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::ExecuteEmergencyStateChange { draw_id: None },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // Verify state changed and the principal is undelegated
         let state: crate::msg::CurrentStateResponse = wasm
-            .query(&contract_address, &QueryMsg::GetCurrentState {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
             .unwrap();
-        assert_eq!(state.state, DrawState::DrawFinished);
+        assert_eq!(state.state, DrawState::Cancelled);
+
+        let delegated: crate::msg::DelegatedAmountResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(delegated.amount.amount, "0");
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ExecuteEmergencyStateChange { draw_id: None },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("No emergency state change scheduled"));
     }
 
     #[test]
@@ -732,7 +937,10 @@ mod tests {
         let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
         let result = wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user1,
         );
@@ -745,6 +953,7 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::BuyTicket {
+                draw_id: None,
                 number_of_tickets: tickets_user1,
             },
             &[coin(tickets_user1.u128() * TICKET_PRICE, FEE_DENOM)],
@@ -755,6 +964,7 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::BuyTicket {
+                draw_id: None,
                 number_of_tickets: tickets_user2,
             },
             &[coin(tickets_user2.u128() * TICKET_PRICE, FEE_DENOM)],
@@ -767,6 +977,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user1.address(),
                 },
             )
@@ -777,6 +988,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user2.address(),
                 },
             )
@@ -814,7 +1026,10 @@ mod tests {
         let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user,
         )
@@ -825,7 +1040,10 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
                 amount: bonus_amount,
+                memo: None,
+                logo_uri: None,
             },
             &[coin(bonus_amount.u128(), FEE_DENOM)],
             &admin,
@@ -850,7 +1068,9 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
                 winner_address: user.address(),
+                runner_up_addresses: vec![],
             },
             &[],
             &admin,
@@ -863,7 +1083,7 @@ mod tests {
         // Send funds to winner
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::SendFundsToWinner {},
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
             &[],
             &admin,
         )
@@ -887,11 +1107,12 @@ mod tests {
 
         // Get expected rewards from contract
         let winner: crate::msg::WinnerResponse = wasm
-            .query(&contract_address, &QueryMsg::GetWinner {})
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: None })
             .unwrap();
 
         // Verify winner address
-        assert_eq!(winner.winner, Some(user.address()));
+        assert_eq!(winner.winners.len(), 1);
+        assert_eq!(winner.winners[0].address, user.address());
 
         // Verify rewards amount
         // The received rewards should be equal to the accumulated rewards + bonus rewards
@@ -907,11 +1128,14 @@ mod tests {
 
         // Get accumulated rewards at undelegation
         let accumulated_rewards_at_undelegation: crate::msg::AccumulatedRewardsAtUndelegationResponse = wasm
-            .query(&contract_address, &QueryMsg::GetAccumulatedRewardsAtUndelegation {})
+            .query(&contract_address, &QueryMsg::GetAccumulatedRewardsAtUndelegation { draw_id: None })
             .unwrap();
 
         let bonus_rewards: crate::msg::BonusRewardsResponse = wasm
-            .query(&contract_address, &QueryMsg::GetBonusRewards {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetBonusRewards { draw_id: None },
+            )
             .unwrap();
 
         assert_eq!(
@@ -966,7 +1190,10 @@ mod tests {
 
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user,
         )
@@ -977,6 +1204,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user.address(),
                 },
             )
@@ -985,7 +1213,10 @@ mod tests {
 
         // Check the delegate amount before undelegation
         let contract_delegated_tokens: crate::msg::DelegatedAmountResponse = wasm
-            .query(&contract_address, &QueryMsg::GetDelegatedAmount {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
             .unwrap();
         println!(
             "contract_delegated_tokens before undelegation: {:?}",
@@ -996,7 +1227,9 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
                 winner_address: user.address(),
+                runner_up_addresses: vec![],
             },
             &[],
             &admin,
@@ -1011,7 +1244,10 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
                 amount: bonus_amount,
+                memo: None,
+                logo_uri: None,
             },
             &[coin(bonus_amount.u128(), FEE_DENOM)],
             &admin,
@@ -1020,12 +1256,18 @@ mod tests {
 
         // Check contract state and balances before sending funds
         let state: crate::msg::CurrentStateResponse = wasm
-            .query(&contract_address, &QueryMsg::GetCurrentState {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
             .unwrap();
         println!("Contract state before sending funds: {:?}", state.state);
 
         let delegated: crate::msg::DelegatedAmountResponse = wasm
-            .query(&contract_address, &QueryMsg::GetDelegatedAmount {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
             .unwrap();
         println!("Delegated amount before sending funds: {:?}", delegated);
 
@@ -1044,7 +1286,7 @@ mod tests {
         // Send funds to winner
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::SendFundsToWinner {},
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
             &[],
             &admin,
         )
@@ -1077,7 +1319,11 @@ mod tests {
 
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BurnTickets { number_of_tickets },
+            &ExecuteMsg::BurnTickets {
+                draw_id: None,
+                number_of_tickets,
+                ticket_subunits: Uint128::zero(),
+            },
             &[tickets_to_burn],
             &user,
         )
@@ -1103,6 +1349,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user.address(),
                 },
             )
@@ -1165,7 +1412,10 @@ mod tests {
 
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::BuyTicket { number_of_tickets },
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
             &[coin(payment.u128(), FEE_DENOM)],
             &user,
         )
@@ -1197,6 +1447,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user.address(),
                 },
             )
@@ -1239,7 +1490,10 @@ mod tests {
 
             wasm.execute(
                 &contract_address,
-                &ExecuteMsg::BuyTicket { number_of_tickets },
+                &ExecuteMsg::BuyTicket {
+                    draw_id: None,
+                    number_of_tickets,
+                },
                 &[coin(payment.u128(), FEE_DENOM)],
                 &user,
             )
@@ -1250,6 +1504,7 @@ mod tests {
                 .query(
                     &contract_address,
                     &QueryMsg::GetUserNumberOfTickets {
+                        draw_id: None,
                         address: user.address(),
                     },
                 )
@@ -1261,6 +1516,7 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::BuyTicket {
+                draw_id: None,
                 number_of_tickets: Uint128::from(1u128),
             },
             &[coin(TICKET_PRICE, FEE_DENOM)],
@@ -1273,6 +1529,7 @@ mod tests {
             .query(
                 &contract_address,
                 &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
                     address: user.address(),
                 },
             )
@@ -1313,6 +1570,7 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::BuyTicket {
+                draw_id: None,
                 number_of_tickets: tickets_user1,
             },
             &[coin(tickets_user1.u128() * TICKET_PRICE, FEE_DENOM)],
@@ -1325,6 +1583,7 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::BuyTicket {
+                draw_id: None,
                 number_of_tickets: tickets_user2,
             },
             &[coin(tickets_user2.u128() * TICKET_PRICE, FEE_DENOM)],
@@ -1334,7 +1593,10 @@ mod tests {
 
         // Query ticket holders
         let holders: crate::msg::TicketHoldersResponse = wasm
-            .query(&contract_address, &QueryMsg::GetTicketHolders {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetTicketHolders { draw_id: None },
+            )
             .unwrap();
 
         println!("Total holders: {}", holders.total_holders);
@@ -1391,7 +1653,10 @@ mod tests {
 
         // Query ticket holders again
         let holders: crate::msg::TicketHoldersResponse = wasm
-            .query(&contract_address, &QueryMsg::GetTicketHolders {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetTicketHolders { draw_id: None },
+            )
             .unwrap();
 
         println!("\nAfter transfer:");
@@ -1468,6 +1733,7 @@ mod tests {
             wasm.execute(
                 &contract_address,
                 &ExecuteMsg::BuyTicket {
+                    draw_id: None,
                     number_of_tickets: Uint128::from(1u128),
                 },
                 &[coin(TICKET_PRICE, FEE_DENOM)],
@@ -1478,13 +1744,19 @@ mod tests {
 
         // Verify total tickets sold
         let tickets_sold: crate::msg::TicketsSoldResponse = wasm
-            .query(&contract_address, &QueryMsg::GetNumberOfTicketsSold {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetNumberOfTicketsSold { draw_id: None },
+            )
             .unwrap();
         assert_eq!(tickets_sold.tickets_sold, Uint128::from(500u128));
 
         // Query ticket holders
         let holders: crate::msg::TicketHoldersResponse = wasm
-            .query(&contract_address, &QueryMsg::GetTicketHolders {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetTicketHolders { draw_id: None },
+            )
             .unwrap();
         assert_eq!(holders.total_holders, 500);
         assert_eq!(holders.holders.len(), 500);
@@ -1508,7 +1780,9 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
                 winner_address: users[0].address(),
+                runner_up_addresses: vec![],
             },
             &[],
             &admin,
@@ -1520,12 +1794,18 @@ mod tests {
 
         // Check contract state and balances before sending funds
         let state: crate::msg::CurrentStateResponse = wasm
-            .query(&contract_address, &QueryMsg::GetCurrentState {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
             .unwrap();
         println!("Contract state before sending funds: {:?}", state.state);
 
         let delegated: crate::msg::DelegatedAmountResponse = wasm
-            .query(&contract_address, &QueryMsg::GetDelegatedAmount {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
             .unwrap();
         println!("Delegated amount before sending funds: {:?}", delegated);
 
@@ -1544,14 +1824,17 @@ mod tests {
         let accumulated_rewards: crate::msg::AccumulatedRewardsAtUndelegationResponse = wasm
             .query(
                 &contract_address,
-                &QueryMsg::GetAccumulatedRewardsAtUndelegation {},
+                &QueryMsg::GetAccumulatedRewardsAtUndelegation { draw_id: None },
             )
             .unwrap();
 
         println!("accumulated_rewards: {:?}", accumulated_rewards);
 
         let bonus_rewards: crate::msg::BonusRewardsResponse = wasm
-            .query(&contract_address, &QueryMsg::GetBonusRewards {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetBonusRewards { draw_id: None },
+            )
             .unwrap();
 
         let total_rewards = accumulated_rewards.accumulated_rewards + bonus_rewards.bonus_rewards;
@@ -1561,7 +1844,7 @@ mod tests {
         // Send funds to winner
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::SendFundsToWinner {},
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
             &[],
             &admin,
         )
@@ -1581,7 +1864,9 @@ mod tests {
             wasm.execute(
                 &contract_address,
                 &ExecuteMsg::BurnTickets {
+                    draw_id: None,
                     number_of_tickets: Uint128::from(1u128),
+                    ticket_subunits: Uint128::zero(),
                 },
                 &[tickets_to_burn],
                 user,
@@ -1590,21 +1875,30 @@ mod tests {
 
             // Check holders after each burn
             let holders: crate::msg::TicketHoldersResponse = wasm
-                .query(&contract_address, &QueryMsg::GetTicketHolders {})
+                .query(
+                    &contract_address,
+                    &QueryMsg::GetTicketHolders { draw_id: None },
+                )
                 .unwrap();
             println!("Holders remaining: {}", holders.total_holders);
         }
 
         // Verify all tickets are burned
         let total_burned: crate::msg::TotalBurnedResponse = wasm
-            .query(&contract_address, &QueryMsg::GetTotalTicketsBurned {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetTotalTicketsBurned { draw_id: None },
+            )
             .unwrap();
         println!("Total tickets burned: {}", total_burned.total_burned);
         assert_eq!(total_burned.total_burned, Uint128::from(500u128));
 
         // Verify no tickets left
         let holders: crate::msg::TicketHoldersResponse = wasm
-            .query(&contract_address, &QueryMsg::GetTicketHolders {})
+            .query(
+                &contract_address,
+                &QueryMsg::GetTicketHolders { draw_id: None },
+            )
             .unwrap();
         println!("Final holders state:");
         println!("Total holders: {}", holders.total_holders);
@@ -1704,6 +1998,7 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::TransferTokenAdmin {
+                draw_id: None,
                 new_admin: new_admin.address(),
             },
             &[],
@@ -1761,7 +2056,9 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
                 winner_address: non_owner.address(),
+                runner_up_addresses: vec![],
             },
             &[],
             &non_owner,
@@ -1779,6 +2076,7 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::UpdateDrawState {
+                draw_id: None,
                 new_state: DrawState::DrawFinished,
             },
             &[],
@@ -1796,7 +2094,7 @@ mod tests {
         // Test execute_send_funds
         let result = wasm.execute(
             &contract_address,
-            &ExecuteMsg::SendFundsToWinner {},
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
             &[],
             &non_owner,
         );
@@ -1813,6 +2111,7 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::SetUndelegationTimestamp {
+                draw_id: None,
                 timestamp: app.get_block_timestamp().seconds() + 1000,
             },
             &[],
@@ -1831,6 +2130,7 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::TransferTokenAdmin {
+                draw_id: None,
                 new_admin: non_owner.address(),
             },
             &[],
@@ -1848,8 +2148,9 @@ mod tests {
         // Verify that owner can still execute these functions
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::UpdateDrawState {
-                new_state: DrawState::DrawFinished,
+            &ExecuteMsg::ScheduleEmergencyStateChange {
+                draw_id: None,
+                new_state: DrawState::Cancelled,
             },
             &[],
             &admin,
@@ -1859,6 +2160,7 @@ mod tests {
         wasm.execute(
             &contract_address,
             &ExecuteMsg::TransferTokenAdmin {
+                draw_id: None,
                 new_admin: non_owner.address(),
             },
             &[],
@@ -1939,8 +2241,9 @@ mod tests {
         // Verify new owner can execute owner-only functions
         wasm.execute(
             &contract_address,
-            &ExecuteMsg::UpdateDrawState {
-                new_state: DrawState::DrawFinished,
+            &ExecuteMsg::ScheduleEmergencyStateChange {
+                draw_id: None,
+                new_state: DrawState::Cancelled,
             },
             &[],
             &new_owner,
@@ -1951,6 +2254,7 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::UpdateDrawState {
+                draw_id: None,
                 new_state: DrawState::TicketSalesOpen,
             },
             &[],
@@ -1978,6 +2282,7 @@ mod tests {
         let result = wasm.execute(
             &contract_address,
             &ExecuteMsg::UpdateDrawState {
+                draw_id: None,
                 new_state: DrawState::TicketSalesOpen,
             },
             &[],
@@ -1992,4 +2297,3731 @@ mod tests {
             "Expected NoOwner error after renouncing ownership"
         );
     }
+
+    #[test]
+    fn test_commit_reveal_winner_selection() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user1 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user2 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        // Commit to the secret while ticket sales are open
+        let secret = HexBinary::from(b"coreum fun secret".to_vec());
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CommitRandomness {
+                draw_id: None,
+                commitment: crate::state::hash_secret(secret.as_slice()),
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // A second commitment is rejected
+        let result = wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CommitRandomness {
+                draw_id: None,
+                commitment: crate::state::hash_secret(b"another secret"),
+            },
+            &[],
+            &admin,
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains(&ContractError::RandomnessAlreadyCommitted {}.to_string()));
+
+        // Sell out the draw
+        for (user, tickets) in [(&user1, 4u128), (&user2, 6u128)] {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: None,
+                    number_of_tickets: Uint128::from(tickets),
+                },
+                &[coin(tickets * TICKET_PRICE, FEE_DENOM)],
+                user,
+            )
+            .unwrap();
+        }
+
+        // The owner can no longer pick the winner directly
+        let result = wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user1.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains(&ContractError::CommitRevealRequired {}.to_string()));
+
+        // Revealing a different secret is rejected
+        let result = wasm.execute(
+            &contract_address,
+            &ExecuteMsg::RevealWinner {
+                draw_id: None,
+                secret: HexBinary::from(b"wrong secret".to_vec()),
+            },
+            &[],
+            &admin,
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains(&ContractError::InvalidRandomnessReveal {}.to_string()));
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::RevealWinner {
+                draw_id: None,
+                secret: secret.clone(),
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // Anyone can re-verify the draw from the stored proof
+        let draw_proof: crate::msg::DrawProofResponse = wasm
+            .query(&contract_address, &QueryMsg::GetDrawProof { draw_id: None })
+            .unwrap();
+        let proof = draw_proof.proof.unwrap();
+
+        assert_eq!(draw_proof.commitment, Some(proof.commitment.clone()));
+        assert_eq!(proof.secret, secret);
+        assert_eq!(
+            crate::state::hash_secret(proof.secret.as_slice()),
+            proof.commitment
+        );
+
+        let seed = crate::state::derive_draw_seed(
+            proof.secret.as_slice(),
+            proof.block_height,
+            proof.block_time_nanos,
+        );
+        assert_eq!(seed, proof.seed);
+        assert_eq!(
+            crate::state::ticket_index_from_seed(&seed, proof.total_tickets),
+            proof.ticket_indexes[0]
+        );
+
+        // Holders own contiguous ranges of TICKET subunits in ascending address order
+        let unit = 10u128.pow(TICKET_PRECISION);
+        let mut holders = vec![(user1.address(), 4 * unit), (user2.address(), 6 * unit)];
+        holders.sort();
+        let expected_winner = if proof.ticket_indexes[0].u128() < holders[0].1 {
+            holders[0].0.clone()
+        } else {
+            holders[1].0.clone()
+        };
+        assert_eq!(proof.winners[0].to_string(), expected_winner);
+
+        let winner: crate::msg::WinnerResponse = wasm
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: None })
+            .unwrap();
+        assert_eq!(winner.winners[0].address, expected_winner);
+
+        let state: crate::msg::CurrentStateResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(state.state, DrawState::WinnerSelectedUndelegationInProcess);
+    }
+
+    #[test]
+    fn test_concurrent_draws() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        // Instantiate contract, which creates draw 1
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),     // total_tickets
+            Uint128::from(TICKET_PRICE), // ticket_price
+            Uint128::from(10u128),       // max_tickets_per_user
+        );
+
+        // Only the owner can create a draw
+        let params = crate::msg::DrawParams {
+            ticket_token_symbol: "TICKETB".to_string(),
+            validators: vec![WeightedValidator {
+                address: validator_address,
+                weight: 1,
+            }],
+            total_tickets: Uint128::from(500u128),
+            ticket_price: Uint128::from(TICKET_PRICE),
+            max_tickets_per_user: Uint128::from(20u128),
+            recurring: false,
+            prize_tiers_bps: vec![],
+            sales_deadline: None,
+            rewards_fallback: None,
+            sales_close: None,
+            min_tickets: Uint128::zero(),
+        };
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::CreateDraw {
+                    params: params.clone(),
+                },
+                &[coin(10_000_000, FEE_DENOM)],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Caller is not the contract's current owner"));
+
+        // Create draw 2 with its own TICKET token (10 CORE issue fee)
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw { params },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        // Buy tickets in both draws
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: Some(1),
+                number_of_tickets: Uint128::from(3u128),
+            },
+            &[coin(3 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: Some(2),
+                number_of_tickets: Uint128::from(15u128),
+            },
+            &[coin(15 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        // Each draw keeps its own counters and TICKET token
+        let sold_1: crate::msg::TicketsSoldResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetNumberOfTicketsSold { draw_id: Some(1) },
+            )
+            .unwrap();
+        assert_eq!(sold_1.tickets_sold, Uint128::from(3u128));
+        assert_eq!(sold_1.total_tickets, Uint128::from(1000u128));
+
+        let sold_2: crate::msg::TicketsSoldResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetNumberOfTicketsSold { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(sold_2.tickets_sold, Uint128::from(15u128));
+        assert_eq!(sold_2.total_tickets, Uint128::from(500u128));
+
+        // Messages without a draw id target the latest draw
+        let user_tickets: crate::msg::UserTicketsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetUserNumberOfTickets {
+                    draw_id: None,
+                    address: user.address(),
+                },
+            )
+            .unwrap();
+        assert_eq!(user_tickets.tickets, Uint128::from(15u128));
+
+        let draws: crate::msg::DrawsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDraws {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(draws.draws.len(), 2);
+        assert_eq!(
+            draws.draws[1].config.ticket_denom,
+            format!("uticketb-{}", contract_address)
+        );
+        assert_eq!(draws.draws[0].config.draw_state, DrawState::TicketSalesOpen);
+    }
+
+    #[test]
+    fn test_recurring_draw_rollover() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        // Create a recurring draw (draw 2)
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw {
+                params: crate::msg::DrawParams {
+                    ticket_token_symbol: "ROUND".to_string(),
+                    validators: vec![WeightedValidator {
+                        address: validator_address,
+                        weight: 1,
+                    }],
+                    total_tickets: Uint128::from(10u128),
+                    ticket_price: Uint128::from(TICKET_PRICE),
+                    max_tickets_per_user: Uint128::from(10u128),
+                    recurring: true,
+                    prize_tiers_bps: vec![],
+                    sales_deadline: None,
+                    rewards_fallback: None,
+                    sales_close: None,
+                    min_tickets: Uint128::zero(),
+                },
+            },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        // Sell out the first round and select the winner
+        let number_of_tickets = Uint128::from(10u128);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: Some(2),
+                number_of_tickets,
+            },
+            &[coin(number_of_tickets.u128() * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: Some(2),
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // Burning the last tickets finishes the round and reopens ticket sales
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 10000000);
+        let ticket_denom = format!("uround-{}", contract_address);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BurnTickets {
+                draw_id: Some(2),
+                number_of_tickets,
+                ticket_subunits: Uint128::zero(),
+            },
+            &[coin(
+                (number_of_tickets * Uint128::from(10u128).pow(TICKET_PRECISION)).u128(),
+                &ticket_denom,
+            )],
+            &user,
+        )
+        .unwrap();
+
+        let draw: crate::state::DrawConfig = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDrawConfig { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(draw.draw_state, DrawState::TicketSalesOpen);
+        assert_eq!(draw.round, 2);
+        assert!(draw.winners.is_empty());
+
+        let tickets_sold: crate::msg::TicketsSoldResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetNumberOfTicketsSold { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(tickets_sold.tickets_sold, Uint128::zero());
+
+        // The finished round is archived
+        let history: crate::msg::RoundHistoryResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetRoundHistory {
+                    draw_id: Some(2),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(history.rounds.len(), 1);
+        assert_eq!(history.rounds[0].round, 1);
+        assert_eq!(history.rounds[0].tickets_sold, number_of_tickets);
+        assert_eq!(history.rounds[0].tickets_burned, number_of_tickets);
+        assert_eq!(history.rounds[0].winners[0].to_string(), user.address());
+
+        // Tickets of the next round can be bought with the same TICKET token
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: Some(2),
+                number_of_tickets: Uint128::from(2u128),
+            },
+            &[coin(2 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        // A non recurring draw is restarted with StartNextRound, only the owner can change params.
+        // Draw 1 sold no ticket, cancelling it finishes it
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::ScheduleEmergencyStateChange {
+                draw_id: Some(1),
+                new_state: DrawState::Cancelled,
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        app.increase_time(SECONDS_PER_DAY * 3);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::ExecuteEmergencyStateChange { draw_id: Some(1) },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        let params = crate::msg::RoundParams {
+            total_tickets: Some(Uint128::from(50u128)),
+            ticket_price: None,
+            max_tickets_per_user: None,
+            recurring: Some(true),
+            prize_tiers_bps: None,
+            sales_deadline: None,
+            sales_close: None,
+            min_tickets: None,
+        };
+        for params in [None, Some(params.clone())] {
+            let err = wasm
+                .execute(
+                    &contract_address,
+                    &ExecuteMsg::StartNextRound {
+                        draw_id: Some(1),
+                        params,
+                    },
+                    &[],
+                    &user,
+                )
+                .unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("Caller is not the contract's current owner"));
+        }
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::StartNextRound {
+                draw_id: Some(1),
+                params: Some(params),
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        let draw: crate::state::DrawConfig = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDrawConfig { draw_id: Some(1) },
+            )
+            .unwrap();
+        assert_eq!(draw.draw_state, DrawState::TicketSalesOpen);
+        assert_eq!(draw.total_tickets, Uint128::from(50u128));
+        assert!(draw.recurring);
+        assert_eq!(draw.round, 2);
+    }
+
+    #[test]
+    fn test_weighted_validator_set() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator_1 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator_2 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let staking = Staking::new(&app);
+        let validator_1 = create_validator(&app, &validator_creator_1);
+        let validator_2 = create_validator(&app, &validator_creator_2);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_1.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        // Invalid validator sets are rejected
+        let mut params = crate::msg::DrawParams {
+            ticket_token_symbol: "SPLIT".to_string(),
+            validators: vec![
+                WeightedValidator {
+                    address: validator_1.clone(),
+                    weight: 1,
+                },
+                WeightedValidator {
+                    address: validator_2.clone(),
+                    weight: 0,
+                },
+            ],
+            total_tickets: Uint128::from(8u128),
+            ticket_price: Uint128::from(TICKET_PRICE),
+            max_tickets_per_user: Uint128::from(8u128),
+            recurring: false,
+            prize_tiers_bps: vec![],
+            sales_deadline: None,
+            rewards_fallback: None,
+            sales_close: None,
+            min_tickets: Uint128::zero(),
+        };
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::CreateDraw {
+                    params: params.clone(),
+                },
+                &[coin(10_000_000, FEE_DENOM)],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid validator set"));
+
+        // Stake 1/4 with the first validator and 3/4 with the second one
+        params.validators[1].weight = 3;
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw { params },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        let number_of_tickets = Uint128::from(8u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: Some(2),
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        let delegation_to = |validator: &str| -> u128 {
+            staking
+                .query_delegation(&QueryDelegationRequest {
+                    delegator_addr: contract_address.clone(),
+                    validator_addr: validator.to_string(),
+                })
+                .unwrap()
+                .delegation_response
+                .unwrap()
+                .balance
+                .unwrap()
+                .amount
+                .parse()
+                .unwrap()
+        };
+        assert_eq!(delegation_to(&validator_1), payment.u128() / 4);
+        assert_eq!(delegation_to(&validator_2), payment.u128() * 3 / 4);
+
+        let delegated: crate::msg::DelegatedAmountResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(delegated.amount.amount, payment.to_string());
+
+        // Undelegation covers every validator of the draw
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: Some(2),
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        let delegated: crate::msg::DelegatedAmountResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(delegated.amount.amount, "0");
+        assert!(staking
+            .query_delegation(&QueryDelegationRequest {
+                delegator_addr: contract_address.clone(),
+                validator_addr: validator_2.clone(),
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn test_redelegate() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator_1 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator_2 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let staking = Staking::new(&app);
+        let validator_1 = create_validator(&app, &validator_creator_1);
+        let validator_2 = create_validator(&app, &validator_creator_2);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_1.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        let number_of_tickets = Uint128::from(5u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        // Only the owner can redelegate
+        let redelegate = ExecuteMsg::Redelegate {
+            draw_id: None,
+            from_validator: None,
+            to_validator: validator_2.clone(),
+        };
+        let err = wasm
+            .execute(&contract_address, &redelegate, &[], &user)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Caller is not the contract's current owner"));
+
+        let res = wasm
+            .execute(&contract_address, &redelegate, &[], &admin)
+            .unwrap();
+        let event = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-redelegate")
+            .expect("redelegate event");
+        assert!(event
+            .attributes
+            .iter()
+            .any(|a| a.key == "dst_validator" && a.value == validator_2));
+
+        // The whole delegation moved to the new validator
+        let delegation = staking
+            .query_delegation(&QueryDelegationRequest {
+                delegator_addr: contract_address.clone(),
+                validator_addr: validator_2.clone(),
+            })
+            .unwrap()
+            .delegation_response
+            .unwrap()
+            .balance
+            .unwrap();
+        assert_eq!(delegation.amount, payment.to_string());
+
+        let draw: crate::state::DrawConfig = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDrawConfig { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(
+            draw.validators,
+            vec![WeightedValidator {
+                address: validator_2.clone(),
+                weight: 1,
+            }]
+        );
+
+        // Nothing is left to move from the old validator
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::Redelegate {
+                    draw_id: None,
+                    from_validator: Some(validator_1),
+                    to_validator: validator_2,
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The draw has no delegation to move"));
+    }
+
+    #[test]
+    fn test_slashing_status() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        let number_of_tickets = Uint128::from(5u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        // Without slashing the whole principal is delegated
+        let status: crate::msg::SlashingStatusResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetSlashingStatus { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(status.expected_principal, payment);
+        assert_eq!(status.actual_principal, payment);
+        assert_eq!(status.loss, Uint128::zero());
+        assert_eq!(status.loss_factor, cosmwasm_std::Decimal::zero());
+
+        // The undelegated principal is recorded when the winner is selected
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        let status: crate::msg::SlashingStatusResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetSlashingStatus { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(status.actual_principal, payment);
+        assert_eq!(status.loss, Uint128::zero());
+    }
+
+    #[test]
+    fn test_withdraw_rewards() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let bank = Bank::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(1000u128),
+        );
+
+        let number_of_tickets = Uint128::from(500u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        // Let rewards accumulate
+        app.increase_time(SECONDS_PER_DAY);
+
+        let contract_balance = |address: &str| -> u128 {
+            bank.query_balance(&QueryBalanceRequest {
+                address: address.to_string(),
+                denom: FEE_DENOM.to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+            .parse()
+            .unwrap()
+        };
+        let balance_before = contract_balance(&contract_address);
+
+        // Anyone can withdraw the rewards to the contract
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::WithdrawRewards {},
+            &[],
+            &user,
+        )
+        .unwrap();
+
+        let rewards: crate::msg::AccumulatedRewardsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetAccumulatedRewards { draw_id: None },
+            )
+            .unwrap();
+        assert!(!rewards.withdrawn_rewards.is_zero());
+        assert_eq!(
+            rewards.accumulated_rewards,
+            rewards.withdrawn_rewards + rewards.pending_rewards
+        );
+
+        // The withdrawn rewards landed in the contract balance
+        let withdrawn = contract_balance(&contract_address) - balance_before;
+        assert!(withdrawn >= rewards.withdrawn_rewards.u128());
+
+        // Buying tickets withdraws the rewards too, they are credited to the draw
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        let after_buy: crate::msg::AccumulatedRewardsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetAccumulatedRewards { draw_id: None },
+            )
+            .unwrap();
+        assert!(after_buy.withdrawn_rewards >= rewards.withdrawn_rewards);
+    }
+
+    #[test]
+    fn test_compound_rewards() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(100u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        // Rewards can only be compounded during the accumulation phase
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::Compound { draw_id: None },
+                &[],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid draw state"));
+
+        let number_of_tickets = Uint128::from(100u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        app.increase_time(SECONDS_PER_DAY);
+
+        // Anyone can compound
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::Compound { draw_id: None },
+            &[],
+            &user,
+        )
+        .unwrap();
+
+        let rewards: crate::msg::AccumulatedRewardsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetAccumulatedRewards { draw_id: None },
+            )
+            .unwrap();
+        assert!(!rewards.compounded_rewards.is_zero());
+        assert_eq!(rewards.compounded_rewards, rewards.withdrawn_rewards);
+
+        // The compounded rewards are delegated on top of the principal
+        let delegated: crate::msg::DelegatedAmountResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(
+            delegated.amount.amount.parse::<Uint128>().unwrap(),
+            payment + rewards.compounded_rewards
+        );
+
+        // Refunds stay exactly the ticket price, the compounded rewards go to the prize
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        let status: crate::msg::SlashingStatusResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetSlashingStatus { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(status.actual_principal, payment);
+
+        let winner: crate::msg::WinnerResponse = wasm
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: None })
+            .unwrap();
+        assert!(winner.rewards >= rewards.compounded_rewards);
+    }
+
+    #[test]
+    fn test_prize_tiers() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let users = app
+            .init_accounts(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)], 4)
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        // Tiers must add up to 10000 basis points
+        let mut params = crate::msg::DrawParams {
+            ticket_token_symbol: "TIERS".to_string(),
+            validators: vec![WeightedValidator {
+                address: validator_address,
+                weight: 1,
+            }],
+            total_tickets: Uint128::from(9u128),
+            ticket_price: Uint128::from(TICKET_PRICE),
+            max_tickets_per_user: Uint128::from(9u128),
+            recurring: false,
+            prize_tiers_bps: vec![6000, 2500, 1000],
+            sales_deadline: None,
+            rewards_fallback: None,
+            sales_close: None,
+            min_tickets: Uint128::zero(),
+        };
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::CreateDraw {
+                    params: params.clone(),
+                },
+                &[coin(10_000_000, FEE_DENOM)],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid prize tiers"));
+
+        params.prize_tiers_bps = vec![6000, 2500, 1500];
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw { params },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        // Three holders buy 3 tickets each and sell the draw out
+        let number_of_tickets = Uint128::from(3u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        for user in users.iter().take(3) {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: Some(2),
+                    number_of_tickets,
+                },
+                &[coin(payment.u128(), FEE_DENOM)],
+                user,
+            )
+            .unwrap();
+        }
+
+        // One distinct ticket holder is required per tier
+        let select = |winner: &SigningAccount, runner_ups: Vec<String>| {
+            ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: Some(2),
+                winner_address: winner.address(),
+                runner_up_addresses: runner_ups,
+            }
+        };
+        let err = wasm
+            .execute(
+                &contract_address,
+                &select(&users[0], vec![users[1].address()]),
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Winners must be distinct"));
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &select(&users[0], vec![users[1].address(), users[0].address()]),
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Winners must be distinct"));
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &select(&users[0], vec![users[1].address(), users[3].address()]),
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("No tickets found for address"));
+
+        wasm.execute(
+            &contract_address,
+            &select(&users[0], vec![users[1].address(), users[2].address()]),
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        let winner: crate::msg::WinnerResponse = wasm
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: Some(2) })
+            .unwrap();
+        assert_eq!(winner.winners.len(), 3);
+        for (index, (user, share_bps)) in users.iter().zip([6000u64, 2500, 1500]).enumerate() {
+            assert_eq!(winner.winners[index].address, user.address());
+            assert_eq!(winner.winners[index].tier, index as u32 + 1);
+            assert_eq!(winner.winners[index].share_bps, share_bps);
+        }
+        let total: Uint128 = winner.winners.iter().map(|w| w.amount).sum();
+        assert_eq!(total, winner.rewards);
+    }
+
+    #[test]
+    fn test_protocol_fee() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let treasury = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let bank = Bank::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(100u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        // The fee is bounded and needs a treasury
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::UpdateFeeConfig {
+                    fee_bps: 5_000,
+                    treasury: Some(treasury.address()),
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Fee too high"));
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::UpdateFeeConfig {
+                    fee_bps: 1_000,
+                    treasury: None,
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("A treasury is required"));
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::UpdateFeeConfig {
+                    fee_bps: 1_000,
+                    treasury: Some(treasury.address()),
+                },
+                &[],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Caller is not the contract's current owner"));
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::UpdateFeeConfig {
+                fee_bps: 1_000,
+                treasury: Some(treasury.address()),
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        let number_of_tickets = Uint128::from(100u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        let bonus_amount = Uint128::from(1_000_000u128);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
+                amount: bonus_amount,
+                memo: None,
+                logo_uri: None,
+            },
+            &[coin(bonus_amount.u128(), FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        // Let rewards accumulate before selecting the winner
+        app.increase_time(SECONDS_PER_DAY * 30);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 1000);
+
+        let balance_of = |address: String| -> u128 {
+            bank.query_balance(&QueryBalanceRequest {
+                address,
+                denom: FEE_DENOM.to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+            .parse()
+            .unwrap()
+        };
+        let treasury_before = balance_of(treasury.address());
+        let user_before = balance_of(user.address());
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // The fee is only taken from the staking rewards
+        let draw: crate::state::DrawConfig = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDrawConfig { draw_id: None },
+            )
+            .unwrap();
+        let expected_fee = draw
+            .accumulated_rewards
+            .multiply_ratio(1_000u128, 10_000u128);
+        assert!(!expected_fee.is_zero());
+
+        let winner: crate::msg::WinnerResponse = wasm
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: None })
+            .unwrap();
+        assert_eq!(winner.fee, expected_fee);
+        assert_eq!(
+            winner.rewards,
+            draw.accumulated_rewards + draw.bonus_rewards - expected_fee
+        );
+
+        assert_eq!(
+            Uint128::from(balance_of(treasury.address()) - treasury_before),
+            expected_fee
+        );
+        assert_eq!(
+            Uint128::from(balance_of(user.address()) - user_before),
+            winner.rewards
+        );
+
+        let fees: crate::msg::FeesCollectedResponse = wasm
+            .query(&contract_address, &QueryMsg::GetFeesCollected {})
+            .unwrap();
+        assert_eq!(fees.total_fees_collected, expected_fee);
+        assert_eq!(fees.fee_bps, 1_000);
+        assert_eq!(fees.treasury, Some(treasury.address()));
+    }
+
+    #[test]
+    fn test_claim_prize() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let users = app
+            .init_accounts(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)], 3)
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let bank = Bank::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw {
+                params: crate::msg::DrawParams {
+                    ticket_token_symbol: "CLAIM".to_string(),
+                    validators: vec![WeightedValidator {
+                        address: validator_address,
+                        weight: 1,
+                    }],
+                    total_tickets: Uint128::from(10u128),
+                    ticket_price: Uint128::from(TICKET_PRICE),
+                    max_tickets_per_user: Uint128::from(10u128),
+                    recurring: false,
+                    prize_tiers_bps: vec![7000, 3000],
+                    sales_deadline: None,
+                    rewards_fallback: None,
+                    sales_close: None,
+                    min_tickets: Uint128::zero(),
+                },
+            },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        let number_of_tickets = Uint128::from(5u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        for user in users.iter().take(2) {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: Some(2),
+                    number_of_tickets,
+                },
+                &[coin(payment.u128(), FEE_DENOM)],
+                user,
+            )
+            .unwrap();
+        }
+
+        let bonus_amount = Uint128::from(1_000_000u128);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: Some(2),
+                amount: bonus_amount,
+                memo: None,
+                logo_uri: None,
+            },
+            &[coin(bonus_amount.u128(), FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: Some(2),
+                winner_address: users[0].address(),
+                runner_up_addresses: vec![users[1].address()],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // Prizes can only be claimed by the winners once the undelegation is complete
+        let claim = ExecuteMsg::ClaimPrize { draw_id: Some(2) };
+        let err = wasm
+            .execute(&contract_address, &claim, &[], &users[0])
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Undelegation period not completed"));
+
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 1000);
+
+        let err = wasm
+            .execute(&contract_address, &claim, &[], &users[2])
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Only a winner of the draw can claim a prize"));
+
+        let balance_of = |address: String| -> u128 {
+            bank.query_balance(&QueryBalanceRequest {
+                address,
+                denom: FEE_DENOM.to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+            .parse()
+            .unwrap()
+        };
+        let winner: crate::msg::WinnerResponse = wasm
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: Some(2) })
+            .unwrap();
+        assert!(winner.winners.iter().all(|w| !w.paid));
+
+        // The first tier winner pulls their prize without the owner
+        let res = wasm
+            .execute(&contract_address, &claim, &[], &users[0])
+            .unwrap();
+        let event = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm")
+            .expect("wasm event");
+        assert!(event
+            .attributes
+            .iter()
+            .any(|a| a.key == "rewards_amount" && a.value == winner.winners[0].amount.to_string()));
+
+        let err = wasm
+            .execute(&contract_address, &claim, &[], &users[0])
+            .unwrap_err();
+        assert!(err.to_string().contains("Prize already paid"));
+
+        // The owner push only pays the prizes still unclaimed
+        let balance_before = balance_of(users[1].address());
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SendFundsToWinner { draw_id: Some(2) },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(
+            balance_of(users[1].address()),
+            balance_before + winner.winners[1].amount.u128()
+        );
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SendFundsToWinner { draw_id: Some(2) },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Prize already paid"));
+
+        let winner: crate::msg::WinnerResponse = wasm
+            .query(&contract_address, &QueryMsg::GetWinner { draw_id: Some(2) })
+            .unwrap();
+        assert!(winner.winners.iter().all(|w| w.paid));
+    }
+
+    #[test]
+    fn test_pause() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let guardian = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        // Only the owner sets the guardian
+        let set_guardian = ExecuteMsg::SetGuardian {
+            guardian: Some(guardian.address()),
+        };
+        let err = wasm
+            .execute(&contract_address, &set_guardian, &[], &user)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Caller is not the contract's current owner"));
+        wasm.execute(&contract_address, &set_guardian, &[], &admin)
+            .unwrap();
+
+        // The guardian pauses buying and admin sends
+        let pause = ExecuteMsg::Pause {
+            operations: vec![Operation::Buy, Operation::AdminSend],
+        };
+        let err = wasm
+            .execute(&contract_address, &pause, &[], &user)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Caller is not the contract's current owner"));
+        wasm.execute(&contract_address, &pause, &[], &guardian)
+            .unwrap();
+
+        let status: crate::msg::PauseStatusResponse = wasm
+            .query(&contract_address, &QueryMsg::GetPauseStatus {})
+            .unwrap();
+        assert!(status.paused.buy && status.paused.admin_send);
+        assert!(!status.paused.burn && !status.paused.payout);
+        assert_eq!(status.guardian, Some(guardian.address()));
+
+        let buy = ExecuteMsg::BuyTicket {
+            draw_id: None,
+            number_of_tickets: Uint128::from(10u128),
+        };
+        let err = wasm
+            .execute(
+                &contract_address,
+                &buy,
+                &[coin(10 * TICKET_PRICE, FEE_DENOM)],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Contract is paused"));
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SendFunds {
+                    recipient: admin.address(),
+                    amount: Uint128::from(1u128),
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Contract is paused"));
+
+        // The guardian cannot unpause, the owner can
+        let unpause = ExecuteMsg::Unpause {
+            operations: vec![Operation::Buy],
+        };
+        let err = wasm
+            .execute(&contract_address, &unpause, &[], &guardian)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Caller is not the contract's current owner"));
+        wasm.execute(&contract_address, &unpause, &[], &admin)
+            .unwrap();
+
+        wasm.execute(
+            &contract_address,
+            &buy,
+            &[coin(10 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        let status: crate::msg::PauseStatusResponse = wasm
+            .query(&contract_address, &QueryMsg::GetPauseStatus {})
+            .unwrap();
+        assert!(!status.paused.buy && status.paused.admin_send);
+
+        // Pausing burns blocks refunds
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 1000);
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::Pause {
+                operations: vec![Operation::Burn],
+            },
+            &[],
+            &guardian,
+        )
+        .unwrap();
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BurnTickets {
+                    draw_id: None,
+                    number_of_tickets: Uint128::from(1u128),
+                    ticket_subunits: Uint128::zero(),
+                },
+                &[],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Contract is paused"));
+    }
+
+    #[test]
+    fn test_cancel_draw() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let users = app
+            .init_accounts(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)], 3)
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        // A draw of 10 tickets that has to sell out within a day
+        let sales_deadline = app.get_block_timestamp().seconds() + SECONDS_PER_DAY;
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw {
+                params: crate::msg::DrawParams {
+                    ticket_token_symbol: "CANCEL".to_string(),
+                    validators: vec![WeightedValidator {
+                        address: validator_address,
+                        weight: 1,
+                    }],
+                    total_tickets: Uint128::from(10u128),
+                    ticket_price: Uint128::from(TICKET_PRICE),
+                    max_tickets_per_user: Uint128::from(10u128),
+                    recurring: false,
+                    prize_tiers_bps: vec![],
+                    sales_deadline: Some(sales_deadline),
+                    rewards_fallback: None,
+                    sales_close: None,
+                    min_tickets: Uint128::zero(),
+                },
+            },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        for (user, tickets) in users.iter().zip([3u128, 2u128]) {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: Some(2),
+                    number_of_tickets: Uint128::from(tickets),
+                },
+                &[coin(tickets * TICKET_PRICE, FEE_DENOM)],
+                user,
+            )
+            .unwrap();
+        }
+
+        let cancel = ExecuteMsg::CancelDraw { draw_id: Some(2) };
+        let err = wasm
+            .execute(&contract_address, &cancel, &[], &users[2])
+            .unwrap_err();
+        assert!(err.to_string().contains("can only be cancelled"));
+
+        // Once the deadline passed, sales are closed and anyone can cancel
+        app.increase_time(SECONDS_PER_DAY + 1);
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: Some(2),
+                    number_of_tickets: Uint128::from(5u128),
+                },
+                &[coin(5 * TICKET_PRICE, FEE_DENOM)],
+                &users[2],
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Ticket sales are closed"));
+
+        wasm.execute(&contract_address, &cancel, &[], &users[2])
+            .unwrap();
+
+        let state: crate::msg::CurrentStateResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(state.state, DrawState::Cancelled);
+
+        let delegated: crate::msg::DelegatedAmountResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDelegatedAmount { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(delegated.amount.amount, "0");
+
+        // Tickets are refunded in full once the undelegation is complete
+        let ticket_denom = format!("ucancel-{}", contract_address);
+        let burn = |user: &SigningAccount, tickets: u128| {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::BurnTickets {
+                    draw_id: Some(2),
+                    number_of_tickets: Uint128::from(tickets),
+                    ticket_subunits: Uint128::zero(),
+                },
+                &[CosmoCoin {
+                    amount: Uint128::from(tickets) * Uint128::from(10u128).pow(TICKET_PRECISION),
+                    denom: ticket_denom.clone(),
+                }],
+                user,
+            )
+        };
+        let err = burn(&users[0], 3).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Undelegation period not completed"));
+
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 1000);
+        for (user, tickets) in users.iter().zip([3u128, 2u128]) {
+            let res = burn(user, tickets).unwrap();
+            let refund: u128 = res
+                .events
+                .iter()
+                .find(|e| e.ty == "wasm")
+                .and_then(|e| e.attributes.iter().find(|a| a.key == "refund_amount"))
+                .unwrap()
+                .value
+                .parse()
+                .unwrap();
+            assert!(refund >= tickets * TICKET_PRICE);
+        }
+
+        let state: crate::msg::CurrentStateResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(state.state, DrawState::DrawFinished);
+    }
+
+    #[test]
+    fn test_time_boxed_draw() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        // Two weekly draws needing 3 and 5 tickets, 4 tickets are sold in each
+        let sales_close = app.get_block_timestamp().seconds() + SECONDS_PER_DAY * 7;
+        for (symbol, min_tickets) in [("WEEKA", 3u128), ("WEEKB", 5u128)] {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::CreateDraw {
+                    params: crate::msg::DrawParams {
+                        ticket_token_symbol: symbol.to_string(),
+                        validators: vec![WeightedValidator {
+                            address: validator_address.clone(),
+                            weight: 1,
+                        }],
+                        total_tickets: Uint128::from(100u128),
+                        ticket_price: Uint128::from(TICKET_PRICE),
+                        max_tickets_per_user: Uint128::from(100u128),
+                        recurring: false,
+                        prize_tiers_bps: vec![],
+                        sales_deadline: None,
+                        rewards_fallback: None,
+                        sales_close: Some(crate::state::SalesClose::Time(sales_close)),
+                        min_tickets: Uint128::from(min_tickets),
+                    },
+                },
+                &[coin(10_000_000, FEE_DENOM)],
+                &admin,
+            )
+            .unwrap();
+        }
+
+        for draw_id in [2u64, 3u64] {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: Some(draw_id),
+                    number_of_tickets: Uint128::from(4u128),
+                },
+                &[coin(4 * TICKET_PRICE, FEE_DENOM)],
+                &user,
+            )
+            .unwrap();
+        }
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::CloseTicketSales { draw_id: Some(2) },
+                &[],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Cannot close ticket sales"));
+
+        // Sales close at the configured time without selling out
+        app.increase_time(SECONDS_PER_DAY * 7 + 1);
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: Some(2),
+                    number_of_tickets: Uint128::from(1u128),
+                },
+                &[coin(TICKET_PRICE, FEE_DENOM)],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Ticket sales are closed"));
+
+        for draw_id in [2u64, 3u64] {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::CloseTicketSales {
+                    draw_id: Some(draw_id),
+                },
+                &[],
+                &user,
+            )
+            .unwrap();
+        }
+
+        let state_of = |draw_id: u64| -> DrawState {
+            wasm.query::<QueryMsg, crate::msg::CurrentStateResponse>(
+                &contract_address,
+                &QueryMsg::GetCurrentState {
+                    draw_id: Some(draw_id),
+                },
+            )
+            .unwrap()
+            .state
+        };
+        assert_eq!(state_of(2), DrawState::TicketsSoldOutAccumulationInProgress);
+        assert_eq!(state_of(3), DrawState::Cancelled);
+
+        // The partially sold draw goes on with the tickets sold
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: Some(2),
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        assert_eq!(state_of(2), DrawState::WinnerSelectedUndelegationInProcess);
+    }
+
+    #[test]
+    fn test_min_accumulation_period() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        let set_period = ExecuteMsg::SetMinAccumulationPeriod {
+            seconds: SECONDS_PER_DAY * 2,
+        };
+        let err = wasm
+            .execute(&contract_address, &set_period, &[], &user)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Caller is not the contract's current owner"));
+        wasm.execute(&contract_address, &set_period, &[], &admin)
+            .unwrap();
+
+        let earliest_draw_time = || -> crate::msg::EarliestDrawTimeResponse {
+            wasm.query(
+                &contract_address,
+                &QueryMsg::GetEarliestDrawTime { draw_id: None },
+            )
+            .unwrap()
+        };
+        assert_eq!(earliest_draw_time().earliest_draw_time, None);
+
+        // Selling out records the sell-out time
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::from(10u128),
+            },
+            &[coin(10 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+        let response = earliest_draw_time();
+        let sold_out_at = response.sold_out_at.unwrap();
+        assert_eq!(
+            response.earliest_draw_time,
+            Some(sold_out_at + SECONDS_PER_DAY * 2)
+        );
+
+        let select_winner = ExecuteMsg::SelectWinnerAndUndelegate {
+            draw_id: None,
+            winner_address: user.address(),
+            runner_up_addresses: vec![],
+        };
+        let err = wasm
+            .execute(&contract_address, &select_winner, &[], &admin)
+            .unwrap_err();
+        assert!(err.to_string().contains("Accumulation period not elapsed"));
+
+        // The winner can be drawn once the period elapsed
+        app.increase_time(SECONDS_PER_DAY * 2);
+        wasm.execute(&contract_address, &select_winner, &[], &admin)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_unbonding_time_from_staking_params() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::from(10u128),
+            },
+            &[coin(10 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        let selected_after = app.get_block_timestamp().seconds();
+        let res = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SelectWinnerAndUndelegate {
+                    draw_id: None,
+                    winner_address: user.address(),
+                    runner_up_addresses: vec![],
+                },
+                &[],
+                &admin,
+            )
+            .unwrap();
+        let selected_before = app.get_block_timestamp().seconds();
+
+        // The undelegation completes after the unbonding time of the chain
+        let unbonding_time = Staking::new(&app)
+            .query_params(&StakingParamsRequest {})
+            .unwrap()
+            .params
+            .unwrap()
+            .unbonding_time
+            .unwrap();
+        let undelegation_done_timestamp = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm")
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|a| a.key == "undelegation_done_timestamp")
+            .unwrap()
+            .value
+            .parse::<u64>()
+            .unwrap();
+        // The block time is rounded up to the next second
+        let selected_at = undelegation_done_timestamp - unbonding_time.seconds as u64;
+        assert!(selected_after <= selected_at && selected_at <= selected_before + 1);
+    }
+
+    #[test]
+    fn test_payout_waits_for_undelegation() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::from(10u128),
+            },
+            &[coin(10 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
+                amount: Uint128::from(1_000_000u128),
+                memo: None,
+                logo_uri: None,
+            },
+            &[coin(1_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // The principal is still unbonding, neither the owner nor the winner can pay out
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SendFundsToWinner { draw_id: None },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Undelegation period not completed"));
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ClaimPrize { draw_id: None },
+                &[],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Undelegation period not completed"));
+
+        // Nor can the owner bring the end of the undelegation forward
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SetUndelegationTimestamp {
+                    draw_id: None,
+                    timestamp: app.get_block_timestamp().seconds(),
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The undelegation timestamp can only be moved later"));
+
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 1000);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
+            &[],
+            &admin,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_solvency() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::from(4u128),
+            },
+            &[coin(4 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        let solvency = || -> crate::msg::SolvencyResponse {
+            wasm.query(&contract_address, &QueryMsg::GetSolvency {})
+                .unwrap()
+        };
+        let status = solvency();
+        assert!(status.solvent);
+        assert_eq!(status.delegated, Uint128::from(4 * TICKET_PRICE));
+        assert_eq!(status.principal_owed, Uint128::from(4 * TICKET_PRICE));
+        assert_eq!(status.unpaid_prizes, Uint128::zero());
+
+        // The owner can only send the surplus, never the principal of the ticket holders
+        let surplus = status.total_assets - status.total_liabilities;
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SendFunds {
+                    recipient: admin.address(),
+                    amount: surplus + Uint128::one(),
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The contract would not cover what it owes"));
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SendFunds {
+                recipient: admin.address(),
+                amount: surplus,
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_withdrawal_timelock() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let guardian = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SetGuardian {
+                guardian: Some(guardian.address()),
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // Every withdrawal is queued behind the default timelock
+        let queued_after = app.get_block_timestamp().seconds();
+        for _ in 0..2 {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::SendFunds {
+                    recipient: admin.address(),
+                    amount: Uint128::from(1_000_000u128),
+                },
+                &[],
+                &admin,
+            )
+            .unwrap();
+        }
+        let pending: crate::msg::PendingWithdrawalsResponse = wasm
+            .query(&contract_address, &QueryMsg::GetPendingWithdrawals {})
+            .unwrap();
+        assert_eq!(pending.withdrawals.len(), 2);
+        assert_eq!(pending.withdrawal_threshold, Uint128::zero());
+        assert!(
+            pending.withdrawals[0].executable_at
+                >= queued_after + pending.withdrawal_timelock_seconds
+        );
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ExecuteWithdrawal { withdrawal_id: 1 },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Withdrawal timelock not expired"));
+
+        // The guardian cancels the second one
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CancelWithdrawal { withdrawal_id: 2 },
+            &[],
+            &guardian,
+        )
+        .unwrap();
+
+        app.increase_time(pending.withdrawal_timelock_seconds);
+        let res = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ExecuteWithdrawal { withdrawal_id: 1 },
+                &[],
+                &admin,
+            )
+            .unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "transfer"));
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ExecuteWithdrawal { withdrawal_id: 2 },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("No pending withdrawal with this id"));
+
+        let pending: crate::msg::PendingWithdrawalsResponse = wasm
+            .query(&contract_address, &QueryMsg::GetPendingWithdrawals {})
+            .unwrap();
+        assert!(pending.withdrawals.is_empty());
+    }
+
+    #[test]
+    fn test_buy_ticket_refunds_overpayment() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let bank = Bank::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+        let contract_balance = || -> u128 {
+            bank.query_balance(&QueryBalanceRequest {
+                address: contract_address.clone(),
+                denom: FEE_DENOM.to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+            .parse()
+            .unwrap()
+        };
+        let balance_before = contract_balance();
+
+        // Paying for three tickets but buying two sends one ticket price back
+        let res = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: None,
+                    number_of_tickets: Uint128::from(2u128),
+                },
+                &[coin(3 * TICKET_PRICE, FEE_DENOM)],
+                &user,
+            )
+            .unwrap();
+        let refund = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm")
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|a| a.key == "refund_amount")
+            .unwrap()
+            .value
+            .clone();
+        assert_eq!(refund, TICKET_PRICE.to_string());
+        // The two tickets paid are delegated, nothing is kept undelegated
+        assert_eq!(contract_balance(), balance_before);
+
+        // Any other denom is rejected
+        let ticket_denom = format!("u{}-{}", TICKET_TOKEN.to_lowercase(), contract_address);
+        let mut funds = vec![
+            coin(TICKET_PRICE, FEE_DENOM),
+            coin(10u128.pow(TICKET_PRECISION), &ticket_denom),
+        ];
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: None,
+                    number_of_tickets: Uint128::one(),
+                },
+                &funds,
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Unexpected denom sent"));
+    }
+
+    #[test]
+    fn test_bonus_contributors() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let sponsor = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        // The sponsor sends more than the amount, the difference is sent back
+        let res = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::AddBonusRewardToThePool {
+                    draw_id: None,
+                    amount: Uint128::from(1_000_000u128),
+                    memo: Some("Sponsored by the community".to_string()),
+                    logo_uri: Some("https://coreum.fun/logo.png".to_string()),
+                },
+                &[coin(1_500_000, FEE_DENOM)],
+                &sponsor,
+            )
+            .unwrap();
+        let refund = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm")
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|a| a.key == "refund_amount")
+            .unwrap()
+            .value
+            .clone();
+        assert_eq!(refund, "500000");
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
+                amount: Uint128::from(2_000_000u128),
+                memo: None,
+                logo_uri: None,
+            },
+            &[coin(2_000_000, FEE_DENOM)],
+            &sponsor,
+        )
+        .unwrap();
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::AddBonusRewardToThePool {
+                draw_id: None,
+                amount: Uint128::from(4_000_000u128),
+                memo: None,
+                logo_uri: None,
+            },
+            &[coin(4_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        let bonus: crate::msg::BonusRewardsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetBonusRewards { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(bonus.bonus_rewards, Uint128::from(7_000_000u128));
+
+        let response: crate::msg::BonusContributorsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetBonusContributors {
+                    draw_id: None,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(response.contributors.len(), 2);
+        let contribution = response
+            .contributors
+            .iter()
+            .find(|c| c.address == sponsor.address())
+            .unwrap();
+        assert_eq!(contribution.amount, Uint128::from(3_000_000u128));
+        assert_eq!(
+            contribution.memo.as_deref(),
+            Some("Sponsored by the community")
+        );
+        assert_eq!(
+            contribution.logo_uri.as_deref(),
+            Some("https://coreum.fun/logo.png")
+        );
+    }
+
+    #[test]
+    fn test_bonus_tokens() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let sponsor = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let bank = Bank::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(10u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        // The sponsor issues its own smart token
+        AssetFT::new(&app)
+            .issue(
+                MsgIssue {
+                    issuer: sponsor.address(),
+                    symbol: "PARTNER".to_string(),
+                    subunit: "upartner".to_string(),
+                    precision: 6,
+                    initial_amount: "1000000000".to_string(),
+                    description: "Partner token".to_string(),
+                    features: vec![],
+                    burn_rate: "0".to_string(),
+                    send_commission_rate: "0".to_string(),
+                    uri: "".to_string(),
+                    uri_hash: "".to_string(),
+                    extension_settings: None,
+                    dex_settings: None,
+                },
+                &sponsor,
+            )
+            .unwrap();
+        let partner_denom = format!("upartner-{}", sponsor.address());
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::from(10u128),
+            },
+            &[coin(10 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        // Bonus rewards in CORE and in the partner token, once the owner accepts it
+        let mut funds = vec![coin(1_000_000, FEE_DENOM), coin(3_000_000, &partner_denom)];
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+        let add_bonus = ExecuteMsg::AddBonusRewardToThePool {
+            draw_id: None,
+            amount: Uint128::from(1_000_000u128),
+            memo: None,
+            logo_uri: None,
+        };
+        let err = wasm
+            .execute(&contract_address, &add_bonus, &funds, &sponsor)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Bonus rewards are not accepted in this denom"));
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SetBonusDenoms {
+                denoms: vec![partner_denom.clone()],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        wasm.execute(&contract_address, &add_bonus, &funds, &sponsor)
+            .unwrap();
+
+        let bonus: crate::msg::BonusRewardsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetBonusRewards { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(bonus.bonus_rewards, Uint128::from(1_000_000u128));
+        assert_eq!(bonus.bonus_coins.len(), 2);
+        assert!(bonus.bonus_coins.contains(&coin(3_000_000, &partner_denom)));
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 1000);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SendFundsToWinner { draw_id: None },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // Every bonus denom is paid to the winner with the CORE prize, nothing is left to claim
+        let partner_balance = bank
+            .query_balance(&QueryBalanceRequest {
+                address: user.address(),
+                denom: partner_denom.clone(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount;
+        assert_eq!(partner_balance, "3000000");
+
+        let claims: crate::msg::BonusTokenClaimsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetBonusTokenClaims {
+                    address: user.address(),
+                },
+            )
+            .unwrap();
+        assert!(claims.tokens.is_empty());
+
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::ClaimBonusTokens {
+                    denom: partner_denom,
+                },
+                &[],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("No bonus tokens to claim"));
+    }
+    #[test]
+    fn test_fractional_tickets() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user1 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user2 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(2u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(2u128),
+        );
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::one(),
+            },
+            &[coin(TICKET_PRICE, FEE_DENOM)],
+            &user1,
+        )
+        .unwrap();
+
+        // Half a ticket changes hands before the sell-out
+        let unit = 10u128.pow(TICKET_PRECISION);
+        let ticket_denom = format!("u{}-{}", TICKET_TOKEN.to_lowercase(), contract_address);
+        Bank::new(&app)
+            .send(
+                MsgSend {
+                    from_address: user1.address(),
+                    to_address: user2.address(),
+                    amount: vec![BaseCoin {
+                        amount: (unit / 2).to_string(),
+                        denom: ticket_denom.clone(),
+                    }],
+                },
+                &user1,
+            )
+            .unwrap();
+
+        // The fraction counts for the win chance
+        let chance: crate::msg::UserWinChanceResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetUserWinChance {
+                    draw_id: None,
+                    address: user2.address(),
+                },
+            )
+            .unwrap();
+        assert_eq!(chance.tickets, Uint128::zero());
+        assert_eq!(chance.ticket_subunits, Uint128::from(unit / 2));
+        assert_eq!(chance.win_chance, "50.00%");
+
+        let holders: crate::msg::TicketHoldersResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetTicketHolders { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(holders.total_holders, 2);
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::one(),
+            },
+            &[coin(TICKET_PRICE, FEE_DENOM)],
+            &user1,
+        )
+        .unwrap();
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user1.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 10000000);
+
+        // A fraction must stay below one ticket
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BurnTickets {
+                    draw_id: None,
+                    number_of_tickets: Uint128::zero(),
+                    ticket_subunits: Uint128::from(unit),
+                },
+                &[coin(unit, &ticket_denom)],
+                &user1,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Ticket subunits must be less than one ticket"));
+
+        // Burning half a ticket refunds half the ticket price
+        let res = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BurnTickets {
+                    draw_id: None,
+                    number_of_tickets: Uint128::zero(),
+                    ticket_subunits: Uint128::from(unit / 2),
+                },
+                &[coin(unit / 2, &ticket_denom)],
+                &user2,
+            )
+            .unwrap();
+        let refund = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm")
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|a| a.key == "refund_amount")
+            .unwrap()
+            .value
+            .clone();
+        assert_eq!(refund, (TICKET_PRICE / 2).to_string());
+
+        // Half a ticket is not a whole ticket burned yet
+        let total_burned: crate::msg::TotalBurnedResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetTotalTicketsBurned { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(total_burned.total_burned, Uint128::zero());
+
+        // The other half and the last ticket finish the draw
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BurnTickets {
+                draw_id: None,
+                number_of_tickets: Uint128::one(),
+                ticket_subunits: Uint128::from(unit / 2),
+            },
+            &[coin(unit + unit / 2, &ticket_denom)],
+            &user1,
+        )
+        .unwrap();
+        let total_burned: crate::msg::TotalBurnedResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetTotalTicketsBurned { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(total_burned.total_burned, Uint128::from(2u128));
+    }
+    #[test]
+    fn test_holder_snapshot_at_sell_out() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user1 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user2 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user3 = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(4u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(4u128),
+        );
+
+        let unit = 10u128.pow(TICKET_PRECISION);
+        let ticket_denom = format!("u{}-{}", TICKET_TOKEN.to_lowercase(), contract_address);
+        let transfer = |from: &SigningAccount, to: &SigningAccount, amount: u128| {
+            Bank::new(&app)
+                .send(
+                    MsgSend {
+                        from_address: from.address(),
+                        to_address: to.address(),
+                        amount: vec![BaseCoin {
+                            amount: amount.to_string(),
+                            denom: ticket_denom.clone(),
+                        }],
+                    },
+                    from,
+                )
+                .unwrap();
+        };
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::from(3u128),
+            },
+            &[coin(3 * TICKET_PRICE, FEE_DENOM)],
+            &user1,
+        )
+        .unwrap();
+
+        // Transfers before the sell-out count, both queries read the bank balances
+        transfer(&user1, &user2, unit);
+        let participants: crate::msg::ParticipantsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetParticipants { draw_id: None },
+            )
+            .unwrap();
+        let holders: crate::msg::TicketHoldersResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetTicketHolders { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(participants.participants, holders.holders);
+        assert_eq!(holders.total_holders, 2);
+
+        // The purchase that sells out freezes the snapshot, the tickets count for their buyer
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::one(),
+            },
+            &[coin(TICKET_PRICE, FEE_DENOM)],
+            &user2,
+        )
+        .unwrap();
+
+        // Transfers after the sell-out don't change the holders of the draw
+        transfer(&user1, &user3, 2 * unit);
+        let participants: crate::msg::ParticipantsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetParticipants { draw_id: None },
+            )
+            .unwrap();
+        let holders: crate::msg::TicketHoldersResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetTicketHolders { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(participants.participants, holders.holders);
+        assert_eq!(holders.total_holders, 2);
+        for holder in &holders.holders {
+            if holder.address == user1.address() {
+                assert_eq!(holder.tickets, Uint128::from(3u128));
+                assert_eq!(holder.win_chance, "75.00%");
+            } else {
+                assert_eq!(holder.address, user2.address());
+                assert_eq!(holder.tickets, Uint128::one());
+                assert_eq!(holder.win_chance, "25.00%");
+            }
+        }
+
+        let chance: crate::msg::UserWinChanceResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetUserWinChance {
+                    draw_id: None,
+                    address: user3.address(),
+                },
+            )
+            .unwrap();
+        assert_eq!(chance.win_chance, "0.00%");
+
+        // Only holders of the snapshot can win
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SelectWinnerAndUndelegate {
+                    draw_id: None,
+                    winner_address: user3.address(),
+                    runner_up_addresses: vec![],
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("No tickets found for address"));
+
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::SelectWinnerAndUndelegate {
+                draw_id: None,
+                winner_address: user1.address(),
+                runner_up_addresses: vec![],
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_cancel_draw_without_winners_after_deadline() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(2u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(2u128),
+        );
+
+        let secret = HexBinary::from(b"never revealed".to_vec());
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CommitRandomness {
+                draw_id: None,
+                commitment: crate::state::hash_secret(secret.as_slice()),
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets: Uint128::from(2u128),
+            },
+            &[coin(2 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        let times: crate::msg::EarliestDrawTimeResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetEarliestDrawTime { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(
+            times.draw_deadline,
+            Some(times.sold_out_at.unwrap() + crate::state::DEFAULT_DRAW_TIMEOUT_SECONDS)
+        );
+
+        // The owner holds the secret, but can only delay the draw until its deadline
+        let cancel = |signer: &SigningAccount| {
+            wasm.execute(
+                &contract_address,
+                &ExecuteMsg::CancelDraw { draw_id: None },
+                &[],
+                signer,
+            )
+        };
+        let err = cancel(&user).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("draw deadline passed without winners"));
+
+        app.increase_time(crate::state::DEFAULT_DRAW_TIMEOUT_SECONDS + 1);
+        cancel(&user).unwrap();
+
+        let state: crate::msg::CurrentStateResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetCurrentState { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(state.state, DrawState::Cancelled);
+
+        // A late reveal can't pick a winner anymore
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::RevealWinner {
+                    draw_id: None,
+                    secret,
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid draw state"));
+    }
+
+    #[test]
+    fn test_withdrawn_rewards_are_liabilities() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(1000u128),
+        );
+
+        let number_of_tickets = Uint128::from(500u128);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(
+                (number_of_tickets * Uint128::from(TICKET_PRICE)).u128(),
+                FEE_DENOM,
+            )],
+            &user,
+        )
+        .unwrap();
+
+        // Let rewards accumulate and withdraw them to the contract balance
+        app.increase_time(SECONDS_PER_DAY);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::WithdrawRewards {},
+            &[],
+            &user,
+        )
+        .unwrap();
+
+        let rewards: crate::msg::AccumulatedRewardsResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetAccumulatedRewards { draw_id: None },
+            )
+            .unwrap();
+        assert!(!rewards.withdrawn_rewards.is_zero());
+
+        // The withdrawn rewards still belong to the prize of the draw
+        let solvency: crate::msg::SolvencyResponse = wasm
+            .query(&contract_address, &QueryMsg::GetSolvency {})
+            .unwrap();
+        assert!(solvency.solvent);
+        assert_eq!(solvency.unpaid_prizes, rewards.withdrawn_rewards);
+
+        // The owner cannot send them away
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SendFunds {
+                    recipient: admin.address(),
+                    amount: rewards.withdrawn_rewards,
+                },
+                &[],
+                &admin,
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The contract would not cover what it owes"));
+    }
+
+    #[test]
+    fn test_cancelled_recurring_draw_pays_no_fee() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let treasury = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::UpdateFeeConfig {
+                fee_bps: 1_000,
+                treasury: Some(treasury.address()),
+            },
+            &[],
+            &admin,
+        )
+        .unwrap();
+
+        // A recurring draw that does not sell out by its deadline
+        let sales_deadline = app.get_block_timestamp().seconds() + SECONDS_PER_DAY;
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw {
+                params: crate::msg::DrawParams {
+                    ticket_token_symbol: "ROLL".to_string(),
+                    validators: vec![WeightedValidator {
+                        address: validator_address,
+                        weight: 1,
+                    }],
+                    total_tickets: Uint128::from(10u128),
+                    ticket_price: Uint128::from(TICKET_PRICE),
+                    max_tickets_per_user: Uint128::from(10u128),
+                    recurring: true,
+                    prize_tiers_bps: vec![],
+                    sales_deadline: Some(sales_deadline),
+                    rewards_fallback: None,
+                    sales_close: None,
+                    min_tickets: Uint128::zero(),
+                },
+            },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: Some(2),
+                number_of_tickets: Uint128::from(5u128),
+            },
+            &[coin(5 * TICKET_PRICE, FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+
+        // Rewards accumulate until the draw is cancelled, they are refunded with the tickets
+        app.increase_time(SECONDS_PER_DAY + 1);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CancelDraw { draw_id: Some(2) },
+            &[],
+            &user,
+        )
+        .unwrap();
+        app.increase_time(SECONDS_PER_DAY * UNDELEGATION_DAYS + 1000);
+
+        // Burning the last ticket rolls the draw over without taking a fee
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BurnTickets {
+                draw_id: Some(2),
+                number_of_tickets: Uint128::from(5u128),
+                ticket_subunits: Uint128::zero(),
+            },
+            &[CosmoCoin {
+                amount: Uint128::from(5u128) * Uint128::from(10u128).pow(TICKET_PRECISION),
+                denom: format!("uroll-{}", contract_address),
+            }],
+            &user,
+        )
+        .unwrap();
+
+        let draw: crate::state::DrawConfig = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDrawConfig { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(draw.draw_state, DrawState::TicketSalesOpen);
+        assert_eq!(draw.round, 2);
+
+        let fees: crate::msg::FeesCollectedResponse = wasm
+            .query(&contract_address, &QueryMsg::GetFeesCollected {})
+            .unwrap();
+        assert_eq!(fees.total_fees_collected, Uint128::zero());
+    }
+
+    #[test]
+    fn test_refunds_wait_for_unbonded_principal() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address,
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(10u128),
+        );
+
+        let number_of_tickets = Uint128::from(5u128);
+        let payment = number_of_tickets * Uint128::from(TICKET_PRICE);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::BuyTicket {
+                draw_id: None,
+                number_of_tickets,
+            },
+            &[coin(payment.u128(), FEE_DENOM)],
+            &user,
+        )
+        .unwrap();
+        let res = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::SelectWinnerAndUndelegate {
+                    draw_id: None,
+                    winner_address: user.address(),
+                    runner_up_addresses: vec![],
+                },
+                &[],
+                &admin,
+            )
+            .unwrap();
+        let undelegation_done_timestamp = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm")
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|a| a.key == "undelegation_done_timestamp")
+            .unwrap()
+            .value
+            .parse::<u64>()
+            .unwrap();
+
+        // Burning as soon as the undelegation is done refunds the whole principal, it is
+        // not measured before the chain released it
+        app.increase_time(undelegation_done_timestamp - app.get_block_timestamp().seconds());
+        let res = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BurnTickets {
+                    draw_id: None,
+                    number_of_tickets,
+                    ticket_subunits: Uint128::zero(),
+                },
+                &[CosmoCoin {
+                    amount: number_of_tickets * Uint128::from(10u128).pow(TICKET_PRECISION),
+                    denom: format!("u{}-{}", TICKET_TOKEN.to_lowercase(), contract_address),
+                }],
+                &user,
+            )
+            .unwrap();
+        let refund = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm")
+            .and_then(|e| e.attributes.iter().find(|a| a.key == "refund_amount"))
+            .unwrap()
+            .value
+            .parse::<u128>()
+            .unwrap();
+        assert_eq!(refund, payment.u128());
+
+        let status: crate::msg::SlashingStatusResponse = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetSlashingStatus { draw_id: None },
+            )
+            .unwrap();
+        assert_eq!(status.actual_principal, payment);
+        assert_eq!(status.loss, Uint128::zero());
+    }
+
+    #[test]
+    fn test_recurring_draw_keeps_sales_deadline() {
+        let app = CoreumTestApp::new();
+        let admin = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let user = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+        let validator_creator = app
+            .init_account(&[coin(100_000_000_000_000_000_000u128, FEE_DENOM)])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let validator_address = create_validator(&app, &validator_creator);
+
+        let contract_address = store_and_instantiate(
+            &wasm,
+            &admin,
+            validator_address.clone(),
+            Uint128::from(1000u128),
+            Uint128::from(TICKET_PRICE),
+            Uint128::from(100u128),
+        );
+
+        // A recurring draw that sells for a day every round
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CreateDraw {
+                params: crate::msg::DrawParams {
+                    ticket_token_symbol: "DAILY".to_string(),
+                    validators: vec![WeightedValidator {
+                        address: validator_address,
+                        weight: 1,
+                    }],
+                    total_tickets: Uint128::from(10u128),
+                    ticket_price: Uint128::from(TICKET_PRICE),
+                    max_tickets_per_user: Uint128::from(10u128),
+                    recurring: true,
+                    prize_tiers_bps: vec![],
+                    sales_deadline: Some(app.get_block_timestamp().seconds() + SECONDS_PER_DAY),
+                    rewards_fallback: None,
+                    sales_close: None,
+                    min_tickets: Uint128::zero(),
+                },
+            },
+            &[coin(10_000_000, FEE_DENOM)],
+            &admin,
+        )
+        .unwrap();
+
+        // Nothing sold by the deadline, the cancelled round finishes and anyone restarts it
+        app.increase_time(SECONDS_PER_DAY + 1);
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::CancelDraw { draw_id: Some(2) },
+            &[],
+            &user,
+        )
+        .unwrap();
+        wasm.execute(
+            &contract_address,
+            &ExecuteMsg::StartNextRound {
+                draw_id: Some(2),
+                params: None,
+            },
+            &[],
+            &user,
+        )
+        .unwrap();
+
+        // The next round closes a day after it started
+        let draw: crate::state::DrawConfig = wasm
+            .query(
+                &contract_address,
+                &QueryMsg::GetDrawConfig { draw_id: Some(2) },
+            )
+            .unwrap();
+        assert_eq!(draw.round, 2);
+        let now = app.get_block_timestamp().seconds();
+        let sales_deadline = draw.sales_deadline.unwrap();
+        assert!(now < sales_deadline && sales_deadline <= now + SECONDS_PER_DAY);
+
+        app.increase_time(SECONDS_PER_DAY + 1);
+        let err = wasm
+            .execute(
+                &contract_address,
+                &ExecuteMsg::BuyTicket {
+                    draw_id: Some(2),
+                    number_of_tickets: Uint128::one(),
+                },
+                &[coin(TICKET_PRICE, FEE_DENOM)],
+                &user,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Ticket sales are closed"));
+    }
+
+    #[test]
+    fn test_migrate_legacy_draw() {
+        use crate::contract::migrate;
+        use crate::msg::MigrateMsg;
+        use crate::state::{
+            LegacyConfig, CLAIMS, DRAWS, FEES_PAID, HOLDER_SNAPSHOT, HOLDER_SNAPSHOT_TOTAL,
+            LEGACY_ACCUMALTED_REWARDS_AT_UNDELEGATION, LEGACY_CLAIMS, LEGACY_CONFIG,
+            LEGACY_TICKET_DENOM, LEGACY_TICKET_HOLDERS, LEGACY_TOTAL_TICKETS_BURNED,
+            LEGACY_TOTAL_TICKETS_SOLD, PRIZES_PAID, TOTAL_TICKETS_SOLD,
+        };
+        use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+        let unit = Uint128::from(10u128).pow(TICKET_PRECISION);
+        let cases = [
+            // The prize was sent at the latest when the undelegation completed
+            (
+                DrawState::UndelegationCompletedTokensCanBeBurned,
+                None,
+                true,
+            ),
+            (DrawState::WinnerSelectedUndelegationInProcess, None, false),
+            // The owner knows the prize was sent early
+            (
+                DrawState::WinnerSelectedUndelegationInProcess,
+                Some(true),
+                true,
+            ),
+        ];
+        for (draw_state, legacy_prize_paid, prize_paid) in cases {
+            let mut deps = mock_dependencies();
+            let winner = deps.api.addr_make("winner");
+            let holder = deps.api.addr_make("holder");
+            let storage = deps.as_mut().storage;
+            cw2::set_contract_version(storage, "coreum-fun", "0.1.0").unwrap();
+            LEGACY_CONFIG
+                .save(
+                    storage,
+                    &LegacyConfig {
+                        ticket_symbol: TICKET_TOKEN.to_string(),
+                        core_denom: FEE_DENOM.to_string(),
+                        validator_address: "validator".to_string(),
+                        total_tickets: Uint128::from(10u128),
+                        max_tickets_per_user: Uint128::from(10u128),
+                        ticket_price: Uint128::from(TICKET_PRICE),
+                        draw_state: draw_state.clone(),
+                        winner: Some(winner.clone()),
+                        undelegation_done_timestamp: Some(1),
+                        accumulated_rewards: Uint128::from(3_000u128),
+                        bonus_rewards: Uint128::from(1_000u128),
+                    },
+                )
+                .unwrap();
+            LEGACY_TICKET_DENOM
+                .save(storage, &"uticket-contract".to_string())
+                .unwrap();
+            LEGACY_TOTAL_TICKETS_SOLD
+                .save(storage, &Uint128::from(10u128))
+                .unwrap();
+            LEGACY_TOTAL_TICKETS_BURNED
+                .save(storage, &Uint128::from(4u128))
+                .unwrap();
+            LEGACY_ACCUMALTED_REWARDS_AT_UNDELEGATION
+                .save(storage, &Uint128::from(3_000u128))
+                .unwrap();
+            LEGACY_TICKET_HOLDERS
+                .save(storage, &winner, &Uint128::from(6u128))
+                .unwrap();
+            LEGACY_TICKET_HOLDERS
+                .save(storage, &holder, &Uint128::from(4u128))
+                .unwrap();
+            LEGACY_CLAIMS
+                .save(storage, &holder, &Uint128::from(4 * TICKET_PRICE))
+                .unwrap();
+
+            let res = migrate(
+                deps.as_mut(),
+                mock_env(),
+                MigrateMsg {
+                    fractional_tickets: None,
+                    legacy_prize_paid,
+                },
+            )
+            .unwrap();
+            assert!(res
+                .attributes
+                .iter()
+                .any(|a| a.key == "migrated_draw_id" && a.value == "1"));
+
+            // The draw, its counters, claims and holders move to draw 1
+            let storage = deps.as_ref().storage;
+            let draw = DRAWS.load(storage, 1).unwrap();
+            assert_eq!(draw.draw_state, draw_state);
+            assert_eq!(draw.winners, vec![winner.clone()]);
+            assert_eq!(
+                TOTAL_TICKETS_SOLD.load(storage, 1).unwrap(),
+                Uint128::from(10u128)
+            );
+            assert_eq!(
+                CLAIMS.load(storage, (1, &holder)).unwrap(),
+                Uint128::from(4 * TICKET_PRICE)
+            );
+            assert_eq!(
+                HOLDER_SNAPSHOT.load(storage, (1, &winner)).unwrap(),
+                Uint128::from(6u128) * unit
+            );
+            assert_eq!(
+                HOLDER_SNAPSHOT_TOTAL.load(storage, 1).unwrap(),
+                Uint128::from(10u128) * unit
+            );
+            assert!(LEGACY_TICKET_HOLDERS.is_empty(storage));
+            assert!(LEGACY_CLAIMS.is_empty(storage));
+
+            // A prize already sent can't be paid again
+            assert_eq!(
+                PRIZES_PAID.may_load(storage, (1, &winner)).unwrap(),
+                prize_paid.then_some(Uint128::from(4_000u128))
+            );
+            assert_eq!(
+                FEES_PAID.may_load(storage, 1).unwrap(),
+                prize_paid.then_some(Uint128::zero())
+            );
+        }
+    }
 }
@@ -6,40 +6,153 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { StdFee } from "@cosmjs/amino";
-import { Uint128, InstantiateMsg, ExecuteMsg, DrawState, QueryMsg, BalanceResponse, AccumulatedRewardsResponse, AccumulatedRewardsAtUndelegationResponse, BonusRewardsResponse, ClaimsResponse, ClaimInfo, CurrentStateResponse, DelegatedAmountResponse, Coin, DraftTvlResponse, TicketsSoldResponse, ParticipantsResponse, ParticipantInfo, TicketHoldersResponse, TotalBurnedResponse, UserTicketsResponse, UserWinChanceResponse, WinnerResponse } from "./CoreumFun.types";
+import { Uint128, SalesClose, InstantiateMsg, WeightedValidator, ExecuteMsg, HexBinary, DrawState, Operation, Action, Expiration, Timestamp, Uint64, DrawParams, RoundParams, QueryMsg, BalanceResponse, AccumulatedRewardsResponse, AccumulatedRewardsAtUndelegationResponse, AllowedTransitionsResponse, EmergencyStateChange, BonusContributorsResponse, BonusContributorInfo, Coin, BonusRewardsResponse, BonusTokenClaimsResponse, ClaimsResponse, ClaimInfo, Addr, Config, CurrentStateResponse, DelegatedAmountResponse, DraftTvlResponse, DrawConfig, DrawProofResponse, DrawProof, DrawsResponse, DrawInfo, EarliestDrawTimeResponse, FeesCollectedResponse, TicketsSoldResponse, ParticipantsResponse, ParticipantInfo, PauseStatusResponse, PauseFlags, PendingWithdrawalsResponse, PendingWithdrawal, RoundHistoryResponse, RoundSummary, Decimal, SlashingStatusResponse, SolvencyResponse, TicketHoldersResponse, TotalBurnedResponse, UserTicketsResponse, UserWinChanceResponse, WinnerResponse, WinnerInfo, OwnershipForString } from "./CoreumFun.types";
 export interface CoreumFunReadOnlyInterface {
   contractAddress: string;
+  getContractConfig: () => Promise<Config>;
+  getPauseStatus: () => Promise<PauseStatusResponse>;
+  getFeesCollected: () => Promise<FeesCollectedResponse>;
+  getDrawConfig: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<DrawConfig>;
+  getDraws: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: number;
+  }) => Promise<DrawsResponse>;
   balance: ({
-    account
+    account,
+    drawId
   }: {
     account: string;
+    drawId?: number;
   }) => Promise<BalanceResponse>;
-  getParticipants: () => Promise<ParticipantsResponse>;
-  getWinner: () => Promise<WinnerResponse>;
-  getCurrentState: () => Promise<CurrentStateResponse>;
-  getNumberOfTicketsSold: () => Promise<TicketsSoldResponse>;
-  getBonusRewards: () => Promise<BonusRewardsResponse>;
-  getAccumulatedRewards: () => Promise<AccumulatedRewardsResponse>;
-  getDraftTvl: () => Promise<DraftTvlResponse>;
-  getTicketHolders: () => Promise<TicketHoldersResponse>;
-  getUserNumberOfTickets: ({
+  getParticipants: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<ParticipantsResponse>;
+  getWinner: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<WinnerResponse>;
+  getCurrentState: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<CurrentStateResponse>;
+  getNumberOfTicketsSold: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<TicketsSoldResponse>;
+  getBonusRewards: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<BonusRewardsResponse>;
+  getBonusContributors: ({
+    drawId,
+    limit,
+    startAfter
+  }: {
+    drawId?: number;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<BonusContributorsResponse>;
+  getBonusTokenClaims: ({
     address
   }: {
     address: string;
+  }) => Promise<BonusTokenClaimsResponse>;
+  getAccumulatedRewards: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<AccumulatedRewardsResponse>;
+  getDraftTvl: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<DraftTvlResponse>;
+  getTicketHolders: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<TicketHoldersResponse>;
+  getUserNumberOfTickets: ({
+    address,
+    drawId
+  }: {
+    address: string;
+    drawId?: number;
   }) => Promise<UserTicketsResponse>;
   getUserWinChance: ({
-    address
+    address,
+    drawId
   }: {
     address: string;
+    drawId?: number;
   }) => Promise<UserWinChanceResponse>;
-  getTotalTicketsBurned: () => Promise<TotalBurnedResponse>;
+  getTotalTicketsBurned: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<TotalBurnedResponse>;
   getClaims: ({
-    address
+    address,
+    drawId
   }: {
     address?: string;
+    drawId?: number;
   }) => Promise<ClaimsResponse>;
-  getDelegatedAmount: () => Promise<DelegatedAmountResponse>;
-  getAccumulatedRewardsAtUndelegation: () => Promise<AccumulatedRewardsAtUndelegationResponse>;
+  getDelegatedAmount: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<DelegatedAmountResponse>;
+  getAccumulatedRewardsAtUndelegation: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<AccumulatedRewardsAtUndelegationResponse>;
+  getDrawProof: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<DrawProofResponse>;
+  getPendingWithdrawals: () => Promise<PendingWithdrawalsResponse>;
+  getSolvency: () => Promise<SolvencyResponse>;
+  getEarliestDrawTime: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<EarliestDrawTimeResponse>;
+  getAllowedTransitions: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<AllowedTransitionsResponse>;
+  getSlashingStatus: ({
+    drawId
+  }: {
+    drawId?: number;
+  }) => Promise<SlashingStatusResponse>;
+  getRoundHistory: ({
+    drawId,
+    limit,
+    startAfter
+  }: {
+    drawId?: number;
+    limit?: number;
+    startAfter?: number;
+  }) => Promise<RoundHistoryResponse>;
+  ownership: () => Promise<OwnershipForString>;
 }
 export class CoreumFunQueryClient implements CoreumFunReadOnlyInterface {
   client: CosmWasmClient;
@@ -47,12 +160,19 @@ export class CoreumFunQueryClient implements CoreumFunReadOnlyInterface {
   constructor(client: CosmWasmClient, contractAddress: string) {
     this.client = client;
     this.contractAddress = contractAddress;
+    this.getContractConfig = this.getContractConfig.bind(this);
+    this.getPauseStatus = this.getPauseStatus.bind(this);
+    this.getFeesCollected = this.getFeesCollected.bind(this);
+    this.getDrawConfig = this.getDrawConfig.bind(this);
+    this.getDraws = this.getDraws.bind(this);
     this.balance = this.balance.bind(this);
     this.getParticipants = this.getParticipants.bind(this);
     this.getWinner = this.getWinner.bind(this);
     this.getCurrentState = this.getCurrentState.bind(this);
     this.getNumberOfTicketsSold = this.getNumberOfTicketsSold.bind(this);
     this.getBonusRewards = this.getBonusRewards.bind(this);
+    this.getBonusContributors = this.getBonusContributors.bind(this);
+    this.getBonusTokenClaims = this.getBonusTokenClaims.bind(this);
     this.getAccumulatedRewards = this.getAccumulatedRewards.bind(this);
     this.getDraftTvl = this.getDraftTvl.bind(this);
     this.getTicketHolders = this.getTicketHolders.bind(this);
@@ -62,120 +182,385 @@ export class CoreumFunQueryClient implements CoreumFunReadOnlyInterface {
     this.getClaims = this.getClaims.bind(this);
     this.getDelegatedAmount = this.getDelegatedAmount.bind(this);
     this.getAccumulatedRewardsAtUndelegation = this.getAccumulatedRewardsAtUndelegation.bind(this);
+    this.getDrawProof = this.getDrawProof.bind(this);
+    this.getPendingWithdrawals = this.getPendingWithdrawals.bind(this);
+    this.getSolvency = this.getSolvency.bind(this);
+    this.getEarliestDrawTime = this.getEarliestDrawTime.bind(this);
+    this.getAllowedTransitions = this.getAllowedTransitions.bind(this);
+    this.getSlashingStatus = this.getSlashingStatus.bind(this);
+    this.getRoundHistory = this.getRoundHistory.bind(this);
+    this.ownership = this.ownership.bind(this);
   }
+  getContractConfig = async (): Promise<Config> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_contract_config: {}
+    });
+  };
+  getPauseStatus = async (): Promise<PauseStatusResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_pause_status: {}
+    });
+  };
+  getFeesCollected = async (): Promise<FeesCollectedResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_fees_collected: {}
+    });
+  };
+  getDrawConfig = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<DrawConfig> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_draw_config: {
+        draw_id: drawId
+      }
+    });
+  };
+  getDraws = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: number;
+  }): Promise<DrawsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_draws: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
   balance = async ({
-    account
+    account,
+    drawId
   }: {
     account: string;
+    drawId?: number;
   }): Promise<BalanceResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       balance: {
-        account
+        account,
+        draw_id: drawId
+      }
+    });
+  };
+  getParticipants = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<ParticipantsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_participants: {
+        draw_id: drawId
+      }
+    });
+  };
+  getWinner = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<WinnerResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_winner: {
+        draw_id: drawId
       }
     });
   };
-  getParticipants = async (): Promise<ParticipantsResponse> => {
+  getCurrentState = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<CurrentStateResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_participants: {}
+      get_current_state: {
+        draw_id: drawId
+      }
     });
   };
-  getWinner = async (): Promise<WinnerResponse> => {
+  getNumberOfTicketsSold = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<TicketsSoldResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_winner: {}
+      get_number_of_tickets_sold: {
+        draw_id: drawId
+      }
     });
   };
-  getCurrentState = async (): Promise<CurrentStateResponse> => {
+  getBonusRewards = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<BonusRewardsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_current_state: {}
+      get_bonus_rewards: {
+        draw_id: drawId
+      }
     });
   };
-  getNumberOfTicketsSold = async (): Promise<TicketsSoldResponse> => {
+  getBonusContributors = async ({
+    drawId,
+    limit,
+    startAfter
+  }: {
+    drawId?: number;
+    limit?: number;
+    startAfter?: string;
+  }): Promise<BonusContributorsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_number_of_tickets_sold: {}
+      get_bonus_contributors: {
+        draw_id: drawId,
+        limit,
+        start_after: startAfter
+      }
     });
   };
-  getBonusRewards = async (): Promise<BonusRewardsResponse> => {
+  getBonusTokenClaims = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<BonusTokenClaimsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_bonus_rewards: {}
+      get_bonus_token_claims: {
+        address
+      }
     });
   };
-  getAccumulatedRewards = async (): Promise<AccumulatedRewardsResponse> => {
+  getAccumulatedRewards = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<AccumulatedRewardsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_accumulated_rewards: {}
+      get_accumulated_rewards: {
+        draw_id: drawId
+      }
     });
   };
-  getDraftTvl = async (): Promise<DraftTvlResponse> => {
+  getDraftTvl = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<DraftTvlResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_draft_tvl: {}
+      get_draft_tvl: {
+        draw_id: drawId
+      }
     });
   };
-  getTicketHolders = async (): Promise<TicketHoldersResponse> => {
+  getTicketHolders = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<TicketHoldersResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_ticket_holders: {}
+      get_ticket_holders: {
+        draw_id: drawId
+      }
     });
   };
   getUserNumberOfTickets = async ({
-    address
+    address,
+    drawId
   }: {
     address: string;
+    drawId?: number;
   }): Promise<UserTicketsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_user_number_of_tickets: {
-        address
+        address,
+        draw_id: drawId
       }
     });
   };
   getUserWinChance = async ({
-    address
+    address,
+    drawId
   }: {
     address: string;
+    drawId?: number;
   }): Promise<UserWinChanceResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_user_win_chance: {
-        address
+        address,
+        draw_id: drawId
       }
     });
   };
-  getTotalTicketsBurned = async (): Promise<TotalBurnedResponse> => {
+  getTotalTicketsBurned = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<TotalBurnedResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_total_tickets_burned: {}
+      get_total_tickets_burned: {
+        draw_id: drawId
+      }
     });
   };
   getClaims = async ({
-    address
+    address,
+    drawId
   }: {
     address?: string;
+    drawId?: number;
   }): Promise<ClaimsResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       get_claims: {
-        address
+        address,
+        draw_id: drawId
+      }
+    });
+  };
+  getDelegatedAmount = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<DelegatedAmountResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_delegated_amount: {
+        draw_id: drawId
+      }
+    });
+  };
+  getAccumulatedRewardsAtUndelegation = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<AccumulatedRewardsAtUndelegationResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_accumulated_rewards_at_undelegation: {
+        draw_id: drawId
+      }
+    });
+  };
+  getDrawProof = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<DrawProofResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_draw_proof: {
+        draw_id: drawId
+      }
+    });
+  };
+  getPendingWithdrawals = async (): Promise<PendingWithdrawalsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_pending_withdrawals: {}
+    });
+  };
+  getSolvency = async (): Promise<SolvencyResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_solvency: {}
+    });
+  };
+  getEarliestDrawTime = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<EarliestDrawTimeResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_earliest_draw_time: {
+        draw_id: drawId
+      }
+    });
+  };
+  getAllowedTransitions = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<AllowedTransitionsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_allowed_transitions: {
+        draw_id: drawId
+      }
+    });
+  };
+  getSlashingStatus = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }): Promise<SlashingStatusResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_slashing_status: {
+        draw_id: drawId
       }
     });
   };
-  getDelegatedAmount = async (): Promise<DelegatedAmountResponse> => {
+  getRoundHistory = async ({
+    drawId,
+    limit,
+    startAfter
+  }: {
+    drawId?: number;
+    limit?: number;
+    startAfter?: number;
+  }): Promise<RoundHistoryResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_delegated_amount: {}
+      get_round_history: {
+        draw_id: drawId,
+        limit,
+        start_after: startAfter
+      }
     });
   };
-  getAccumulatedRewardsAtUndelegation = async (): Promise<AccumulatedRewardsAtUndelegationResponse> => {
+  ownership = async (): Promise<OwnershipForString> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      get_accumulated_rewards_at_undelegation: {}
+      ownership: {}
     });
   };
 }
 export interface CoreumFunInterface extends CoreumFunReadOnlyInterface {
   contractAddress: string;
   sender: string;
+  createDraw: ({
+    params
+  }: {
+    params: DrawParams;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
   buyTicket: ({
+    drawId,
     numberOfTickets
   }: {
+    drawId?: number;
     numberOfTickets: Uint128;
   }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  cancelDraw: ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  closeTicketSales: ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
   selectWinnerAndUndelegate: ({
+    drawId,
+    runnerUpAddresses,
     winnerAddress
   }: {
+    drawId?: number;
+    runnerUpAddresses?: string[];
     winnerAddress: string;
   }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  commitRandomness: ({
+    commitment,
+    drawId
+  }: {
+    commitment: HexBinary;
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  revealWinner: ({
+    drawId,
+    secret
+  }: {
+    drawId?: number;
+    secret: HexBinary;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
   sendFunds: ({
     amount,
     recipient
@@ -183,27 +568,144 @@ export interface CoreumFunInterface extends CoreumFunReadOnlyInterface {
     amount: Uint128;
     recipient: string;
   }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  executeWithdrawal: ({
+    withdrawalId
+  }: {
+    withdrawalId: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  cancelWithdrawal: ({
+    withdrawalId
+  }: {
+    withdrawalId: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
   burnTickets: ({
-    numberOfTickets
+    drawId,
+    numberOfTickets,
+    ticketSubunits
   }: {
+    drawId?: number;
     numberOfTickets: Uint128;
+    ticketSubunits?: Uint128;
   }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
   addBonusRewardToThePool: ({
-    amount
+    amount,
+    drawId,
+    logoUri,
+    memo
   }: {
     amount: Uint128;
+    drawId?: number;
+    logoUri?: string;
+    memo?: string;
   }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
   updateDrawState: ({
+    drawId,
     newState
   }: {
+    drawId?: number;
     newState: DrawState;
   }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  scheduleEmergencyStateChange: ({
+    drawId,
+    newState
+  }: {
+    drawId?: number;
+    newState: DrawState;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  executeEmergencyStateChange: ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  cancelEmergencyStateChange: ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
   setUndelegationTimestamp: ({
+    drawId,
     timestamp
   }: {
+    drawId?: number;
     timestamp: number;
   }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
-  sendFundsToWinner: (fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  sendFundsToWinner: ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  claimPrize: ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  claimBonusTokens: ({
+    denom
+  }: {
+    denom: string;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  transferTokenAdmin: ({
+    drawId,
+    newAdmin
+  }: {
+    drawId?: number;
+    newAdmin: string;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  withdrawRewards: (fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  compound: ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  redelegate: ({
+    drawId,
+    fromValidator,
+    toValidator
+  }: {
+    drawId?: number;
+    fromValidator?: string;
+    toValidator: string;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  startNextRound: ({
+    drawId,
+    params
+  }: {
+    drawId?: number;
+    params?: RoundParams;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  pause: ({
+    operations
+  }: {
+    operations: Operation[];
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  unpause: ({
+    operations
+  }: {
+    operations: Operation[];
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  setGuardian: ({
+    guardian
+  }: {
+    guardian?: string;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  setMinAccumulationPeriod: ({
+    seconds
+  }: {
+    seconds: number;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  updateFeeConfig: ({
+    feeBps,
+    treasury
+  }: {
+    feeBps: number;
+    treasury?: string;
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  setBonusDenoms: ({
+    denoms
+  }: {
+    denoms: string[];
+  }, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
+  updateOwnership: (action: Action, fee_?: number | StdFee | "auto", memo_?: string, funds_?: Coin[]) => Promise<ExecuteResult>;
 }
 export class CoreumFunClient extends CoreumFunQueryClient implements CoreumFunInterface {
   client: SigningCosmWasmClient;
@@ -214,37 +716,131 @@ export class CoreumFunClient extends CoreumFunQueryClient implements CoreumFunIn
     this.client = client;
     this.sender = sender;
     this.contractAddress = contractAddress;
+    this.createDraw = this.createDraw.bind(this);
     this.buyTicket = this.buyTicket.bind(this);
+    this.cancelDraw = this.cancelDraw.bind(this);
+    this.closeTicketSales = this.closeTicketSales.bind(this);
     this.selectWinnerAndUndelegate = this.selectWinnerAndUndelegate.bind(this);
+    this.commitRandomness = this.commitRandomness.bind(this);
+    this.revealWinner = this.revealWinner.bind(this);
     this.sendFunds = this.sendFunds.bind(this);
+    this.executeWithdrawal = this.executeWithdrawal.bind(this);
+    this.cancelWithdrawal = this.cancelWithdrawal.bind(this);
     this.burnTickets = this.burnTickets.bind(this);
     this.addBonusRewardToThePool = this.addBonusRewardToThePool.bind(this);
     this.updateDrawState = this.updateDrawState.bind(this);
+    this.scheduleEmergencyStateChange = this.scheduleEmergencyStateChange.bind(this);
+    this.executeEmergencyStateChange = this.executeEmergencyStateChange.bind(this);
+    this.cancelEmergencyStateChange = this.cancelEmergencyStateChange.bind(this);
     this.setUndelegationTimestamp = this.setUndelegationTimestamp.bind(this);
     this.sendFundsToWinner = this.sendFundsToWinner.bind(this);
+    this.claimPrize = this.claimPrize.bind(this);
+    this.claimBonusTokens = this.claimBonusTokens.bind(this);
+    this.transferTokenAdmin = this.transferTokenAdmin.bind(this);
+    this.withdrawRewards = this.withdrawRewards.bind(this);
+    this.compound = this.compound.bind(this);
+    this.redelegate = this.redelegate.bind(this);
+    this.startNextRound = this.startNextRound.bind(this);
+    this.pause = this.pause.bind(this);
+    this.unpause = this.unpause.bind(this);
+    this.setGuardian = this.setGuardian.bind(this);
+    this.setMinAccumulationPeriod = this.setMinAccumulationPeriod.bind(this);
+    this.updateFeeConfig = this.updateFeeConfig.bind(this);
+    this.setBonusDenoms = this.setBonusDenoms.bind(this);
+    this.updateOwnership = this.updateOwnership.bind(this);
   }
+  createDraw = async ({
+    params
+  }: {
+    params: DrawParams;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      create_draw: {
+        params
+      }
+    }, fee_, memo_, funds_);
+  };
   buyTicket = async ({
+    drawId,
     numberOfTickets
   }: {
+    drawId?: number;
     numberOfTickets: Uint128;
   }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       buy_ticket: {
+        draw_id: drawId,
         number_of_tickets: numberOfTickets
       }
     }, fee_, memo_, funds_);
   };
+  cancelDraw = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_draw: {
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
+  closeTicketSales = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      close_ticket_sales: {
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
   selectWinnerAndUndelegate = async ({
+    drawId,
+    runnerUpAddresses,
     winnerAddress
   }: {
+    drawId?: number;
+    runnerUpAddresses?: string[];
     winnerAddress: string;
   }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       select_winner_and_undelegate: {
+        draw_id: drawId,
+        runner_up_addresses: runnerUpAddresses,
         winner_address: winnerAddress
       }
     }, fee_, memo_, funds_);
   };
+  commitRandomness = async ({
+    commitment,
+    drawId
+  }: {
+    commitment: HexBinary;
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      commit_randomness: {
+        commitment,
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
+  revealWinner = async ({
+    drawId,
+    secret
+  }: {
+    drawId?: number;
+    secret: HexBinary;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reveal_winner: {
+        draw_id: drawId,
+        secret
+      }
+    }, fee_, memo_, funds_);
+  };
   sendFunds = async ({
     amount,
     recipient
@@ -259,53 +855,295 @@ export class CoreumFunClient extends CoreumFunQueryClient implements CoreumFunIn
       }
     }, fee_, memo_, funds_);
   };
+  executeWithdrawal = async ({
+    withdrawalId
+  }: {
+    withdrawalId: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      execute_withdrawal: {
+        withdrawal_id: withdrawalId
+      }
+    }, fee_, memo_, funds_);
+  };
+  cancelWithdrawal = async ({
+    withdrawalId
+  }: {
+    withdrawalId: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_withdrawal: {
+        withdrawal_id: withdrawalId
+      }
+    }, fee_, memo_, funds_);
+  };
   burnTickets = async ({
-    numberOfTickets
+    drawId,
+    numberOfTickets,
+    ticketSubunits
   }: {
+    drawId?: number;
     numberOfTickets: Uint128;
+    ticketSubunits?: Uint128;
   }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       burn_tickets: {
-        number_of_tickets: numberOfTickets
+        draw_id: drawId,
+        number_of_tickets: numberOfTickets,
+        ticket_subunits: ticketSubunits
       }
     }, fee_, memo_, funds_);
   };
   addBonusRewardToThePool = async ({
-    amount
+    amount,
+    drawId,
+    logoUri,
+    memo
   }: {
     amount: Uint128;
+    drawId?: number;
+    logoUri?: string;
+    memo?: string;
   }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       add_bonus_reward_to_the_pool: {
-        amount
+        amount,
+        draw_id: drawId,
+        logo_uri: logoUri,
+        memo
       }
     }, fee_, memo_, funds_);
   };
   updateDrawState = async ({
+    drawId,
     newState
   }: {
+    drawId?: number;
     newState: DrawState;
   }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       update_draw_state: {
+        draw_id: drawId,
         new_state: newState
       }
     }, fee_, memo_, funds_);
   };
+  scheduleEmergencyStateChange = async ({
+    drawId,
+    newState
+  }: {
+    drawId?: number;
+    newState: DrawState;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      schedule_emergency_state_change: {
+        draw_id: drawId,
+        new_state: newState
+      }
+    }, fee_, memo_, funds_);
+  };
+  executeEmergencyStateChange = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      execute_emergency_state_change: {
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
+  cancelEmergencyStateChange = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_emergency_state_change: {
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
   setUndelegationTimestamp = async ({
+    drawId,
     timestamp
   }: {
+    drawId?: number;
     timestamp: number;
   }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       set_undelegation_timestamp: {
+        draw_id: drawId,
         timestamp
       }
     }, fee_, memo_, funds_);
   };
-  sendFundsToWinner = async (fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+  sendFundsToWinner = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      send_funds_to_winner: {
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
+  claimPrize = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_prize: {
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
+  claimBonusTokens = async ({
+    denom
+  }: {
+    denom: string;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_bonus_tokens: {
+        denom
+      }
+    }, fee_, memo_, funds_);
+  };
+  transferTokenAdmin = async ({
+    drawId,
+    newAdmin
+  }: {
+    drawId?: number;
+    newAdmin: string;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      transfer_token_admin: {
+        draw_id: drawId,
+        new_admin: newAdmin
+      }
+    }, fee_, memo_, funds_);
+  };
+  withdrawRewards = async (fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_rewards: {}
+    }, fee_, memo_, funds_);
+  };
+  compound = async ({
+    drawId
+  }: {
+    drawId?: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      compound: {
+        draw_id: drawId
+      }
+    }, fee_, memo_, funds_);
+  };
+  redelegate = async ({
+    drawId,
+    fromValidator,
+    toValidator
+  }: {
+    drawId?: number;
+    fromValidator?: string;
+    toValidator: string;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      redelegate: {
+        draw_id: drawId,
+        from_validator: fromValidator,
+        to_validator: toValidator
+      }
+    }, fee_, memo_, funds_);
+  };
+  startNextRound = async ({
+    drawId,
+    params
+  }: {
+    drawId?: number;
+    params?: RoundParams;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      start_next_round: {
+        draw_id: drawId,
+        params
+      }
+    }, fee_, memo_, funds_);
+  };
+  pause = async ({
+    operations
+  }: {
+    operations: Operation[];
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      pause: {
+        operations
+      }
+    }, fee_, memo_, funds_);
+  };
+  unpause = async ({
+    operations
+  }: {
+    operations: Operation[];
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unpause: {
+        operations
+      }
+    }, fee_, memo_, funds_);
+  };
+  setGuardian = async ({
+    guardian
+  }: {
+    guardian?: string;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_guardian: {
+        guardian
+      }
+    }, fee_, memo_, funds_);
+  };
+  setMinAccumulationPeriod = async ({
+    seconds
+  }: {
+    seconds: number;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_min_accumulation_period: {
+        seconds
+      }
+    }, fee_, memo_, funds_);
+  };
+  updateFeeConfig = async ({
+    feeBps,
+    treasury
+  }: {
+    feeBps: number;
+    treasury?: string;
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_fee_config: {
+        fee_bps: feeBps,
+        treasury
+      }
+    }, fee_, memo_, funds_);
+  };
+  setBonusDenoms = async ({
+    denoms
+  }: {
+    denoms: string[];
+  }, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_bonus_denoms: {
+        denoms
+      }
+    }, fee_, memo_, funds_);
+  };
+  updateOwnership = async (action: Action, fee_: number | StdFee | "auto" = "auto", memo_?: string, funds_?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      send_funds_to_winner: {}
+      update_ownership: action
     }, fee_, memo_, funds_);
   };
 }
\ No newline at end of file
@@ -5,98 +5,384 @@
 */
 
 export type Uint128 = string;
+export type SalesClose = {
+  Time: number;
+} | {
+  Height: number;
+};
 export interface InstantiateMsg {
   core_denom: string;
+  draw_timeout_seconds?: number | null;
+  fallback_unbonding_seconds?: number | null;
+  fee_bps?: number;
+  fractional_tickets?: boolean;
+  guardian?: string | null;
   max_tickets_per_user: Uint128;
+  min_accumulation_seconds?: number;
+  min_tickets?: Uint128;
+  prize_tiers_bps?: number[];
+  recurring?: boolean;
+  rewards_fallback?: string | null;
+  sales_close?: SalesClose | null;
+  sales_deadline?: number | null;
   ticket_price: Uint128;
   ticket_token_symbol: string;
   total_tickets: Uint128;
-  validator_address: string;
+  treasury?: string | null;
+  validators: WeightedValidator[];
+  withdrawal_threshold?: Uint128 | null;
+  withdrawal_timelock_seconds?: number | null;
+}
+export interface WeightedValidator {
+  address: string;
+  weight: number;
 }
 export type ExecuteMsg = {
+  create_draw: {
+    params: DrawParams;
+  };
+} | {
   buy_ticket: {
+    draw_id?: number | null;
     number_of_tickets: Uint128;
   };
+} | {
+  cancel_draw: {
+    draw_id?: number | null;
+  };
+} | {
+  close_ticket_sales: {
+    draw_id?: number | null;
+  };
 } | {
   select_winner_and_undelegate: {
+    draw_id?: number | null;
+    runner_up_addresses?: string[];
     winner_address: string;
   };
+} | {
+  commit_randomness: {
+    commitment: HexBinary;
+    draw_id?: number | null;
+  };
+} | {
+  reveal_winner: {
+    draw_id?: number | null;
+    secret: HexBinary;
+  };
 } | {
   send_funds: {
     amount: Uint128;
     recipient: string;
   };
+} | {
+  execute_withdrawal: {
+    withdrawal_id: number;
+  };
+} | {
+  cancel_withdrawal: {
+    withdrawal_id: number;
+  };
 } | {
   burn_tickets: {
+    draw_id?: number | null;
     number_of_tickets: Uint128;
+    ticket_subunits?: Uint128;
   };
 } | {
   add_bonus_reward_to_the_pool: {
     amount: Uint128;
+    draw_id?: number | null;
+    logo_uri?: string | null;
+    memo?: string | null;
   };
 } | {
   update_draw_state: {
+    draw_id?: number | null;
     new_state: DrawState;
   };
+} | {
+  schedule_emergency_state_change: {
+    draw_id?: number | null;
+    new_state: DrawState;
+  };
+} | {
+  execute_emergency_state_change: {
+    draw_id?: number | null;
+  };
+} | {
+  cancel_emergency_state_change: {
+    draw_id?: number | null;
+  };
 } | {
   set_undelegation_timestamp: {
+    draw_id?: number | null;
     timestamp: number;
   };
 } | {
-  send_funds_to_winner: {};
+  send_funds_to_winner: {
+    draw_id?: number | null;
+  };
+} | {
+  claim_prize: {
+    draw_id?: number | null;
+  };
+} | {
+  claim_bonus_tokens: {
+    denom: string;
+  };
+} | {
+  transfer_token_admin: {
+    draw_id?: number | null;
+    new_admin: string;
+  };
+} | {
+  withdraw_rewards: {};
+} | {
+  compound: {
+    draw_id?: number | null;
+  };
+} | {
+  redelegate: {
+    draw_id?: number | null;
+    from_validator?: string | null;
+    to_validator: string;
+  };
+} | {
+  start_next_round: {
+    draw_id?: number | null;
+    params?: RoundParams | null;
+  };
+} | {
+  pause: {
+    operations: Operation[];
+  };
+} | {
+  unpause: {
+    operations: Operation[];
+  };
+} | {
+  set_guardian: {
+    guardian?: string | null;
+  };
+} | {
+  set_min_accumulation_period: {
+    seconds: number;
+  };
+} | {
+  update_fee_config: {
+    fee_bps: number;
+    treasury?: string | null;
+  };
+} | {
+  set_bonus_denoms: {
+    denoms: string[];
+  };
+} | {
+  update_ownership: Action;
+};
+export type HexBinary = string;
+export type DrawState = "TicketSalesOpen" | "TicketsSoldOutAccumulationInProgress" | "WinnerSelectedUndelegationInProcess" | "UndelegationCompletedTokensCanBeBurned" | "DrawFinished" | "Cancelled";
+export type Operation = "Buy" | "Burn" | "Payout" | "AdminSend";
+export type Action = {
+  transfer_ownership: {
+    expiry?: Expiration | null;
+    new_owner: string;
+  };
+} | "accept_ownership" | "renounce_ownership";
+export type Expiration = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+} | {
+  never: {};
 };
-export type DrawState = "TicketSalesOpen" | "TicketsSoldOutAccumulationInProgress" | "WinnerSelectedUndelegationInProcess" | "UndelegationCompletedTokensCanBeBurned" | "DrawFinished";
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export interface DrawParams {
+  max_tickets_per_user: Uint128;
+  min_tickets?: Uint128;
+  prize_tiers_bps?: number[];
+  recurring?: boolean;
+  rewards_fallback?: string | null;
+  sales_close?: SalesClose | null;
+  sales_deadline?: number | null;
+  ticket_price: Uint128;
+  ticket_token_symbol: string;
+  total_tickets: Uint128;
+  validators: WeightedValidator[];
+}
+export interface RoundParams {
+  max_tickets_per_user?: Uint128 | null;
+  min_tickets?: Uint128 | null;
+  prize_tiers_bps?: number[] | null;
+  recurring?: boolean | null;
+  sales_close?: SalesClose | null;
+  sales_deadline?: number | null;
+  ticket_price?: Uint128 | null;
+  total_tickets?: Uint128 | null;
+}
 export type QueryMsg = {
+  get_contract_config: {};
+} | {
+  get_pause_status: {};
+} | {
+  get_fees_collected: {};
+} | {
+  get_draw_config: {
+    draw_id?: number | null;
+  };
+} | {
+  get_draws: {
+    limit?: number | null;
+    start_after?: number | null;
+  };
+} | {
   balance: {
     account: string;
+    draw_id?: number | null;
   };
 } | {
-  get_participants: {};
+  get_participants: {
+    draw_id?: number | null;
+  };
 } | {
-  get_winner: {};
+  get_winner: {
+    draw_id?: number | null;
+  };
 } | {
-  get_current_state: {};
+  get_current_state: {
+    draw_id?: number | null;
+  };
 } | {
-  get_number_of_tickets_sold: {};
+  get_number_of_tickets_sold: {
+    draw_id?: number | null;
+  };
 } | {
-  get_bonus_rewards: {};
+  get_bonus_rewards: {
+    draw_id?: number | null;
+  };
 } | {
-  get_accumulated_rewards: {};
+  get_bonus_contributors: {
+    draw_id?: number | null;
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  get_bonus_token_claims: {
+    address: string;
+  };
+} | {
+  get_accumulated_rewards: {
+    draw_id?: number | null;
+  };
 } | {
-  get_draft_tvl: {};
+  get_draft_tvl: {
+    draw_id?: number | null;
+  };
 } | {
-  get_ticket_holders: {};
+  get_ticket_holders: {
+    draw_id?: number | null;
+  };
 } | {
   get_user_number_of_tickets: {
     address: string;
+    draw_id?: number | null;
   };
 } | {
   get_user_win_chance: {
     address: string;
+    draw_id?: number | null;
   };
 } | {
-  get_total_tickets_burned: {};
+  get_total_tickets_burned: {
+    draw_id?: number | null;
+  };
 } | {
   get_claims: {
     address?: string | null;
+    draw_id?: number | null;
   };
 } | {
-  get_delegated_amount: {};
+  get_delegated_amount: {
+    draw_id?: number | null;
+  };
 } | {
-  get_accumulated_rewards_at_undelegation: {};
+  get_accumulated_rewards_at_undelegation: {
+    draw_id?: number | null;
+  };
+} | {
+  get_draw_proof: {
+    draw_id?: number | null;
+  };
+} | {
+  get_pending_withdrawals: {};
+} | {
+  get_solvency: {};
+} | {
+  get_earliest_draw_time: {
+    draw_id?: number | null;
+  };
+} | {
+  get_allowed_transitions: {
+    draw_id?: number | null;
+  };
+} | {
+  get_slashing_status: {
+    draw_id?: number | null;
+  };
+} | {
+  get_round_history: {
+    draw_id?: number | null;
+    limit?: number | null;
+    start_after?: number | null;
+  };
+} | {
+  ownership: {};
 };
 export interface BalanceResponse {
   balance: Uint128;
 }
 export interface AccumulatedRewardsResponse {
   accumulated_rewards: Uint128;
+  compounded_rewards: Uint128;
+  pending_rewards: Uint128;
+  withdrawn_rewards: Uint128;
 }
 export interface AccumulatedRewardsAtUndelegationResponse {
   accumulated_rewards: Uint128;
 }
+export interface AllowedTransitionsResponse {
+  allowed_transitions: DrawState[];
+  current_state: DrawState;
+  emergency_state_change?: EmergencyStateChange | null;
+}
+export interface EmergencyStateChange {
+  executable_at: number;
+  from_state: DrawState;
+  new_state: DrawState;
+}
+export interface BonusContributorsResponse {
+  contributors: BonusContributorInfo[];
+}
+export interface BonusContributorInfo {
+  address: string;
+  amount: Uint128;
+  logo_uri?: string | null;
+  memo?: string | null;
+  tokens: Coin[];
+}
+export interface Coin {
+  amount: Uint128;
+  denom: string;
+}
 export interface BonusRewardsResponse {
+  bonus_coins: Coin[];
   bonus_rewards: Uint128;
 }
+export interface BonusTokenClaimsResponse {
+  tokens: Coin[];
+}
 export interface ClaimsResponse {
   claims: ClaimInfo[];
   total_claimed: Uint128;
@@ -105,6 +391,20 @@ export interface ClaimInfo {
   address: string;
   amount: Uint128;
 }
+export type Addr = string;
+export interface Config {
+  bonus_denoms?: string[];
+  core_denom: string;
+  draw_timeout_seconds?: number;
+  fallback_unbonding_seconds?: number;
+  fee_bps?: number;
+  fractional_tickets?: boolean;
+  guardian?: Addr | null;
+  min_accumulation_seconds?: number;
+  treasury?: Addr | null;
+  withdrawal_threshold?: Uint128;
+  withdrawal_timelock_seconds?: number;
+}
 export interface CurrentStateResponse {
   state: DrawState;
   undelegation_done_timestamp?: number | null;
@@ -112,14 +412,65 @@ export interface CurrentStateResponse {
 export interface DelegatedAmountResponse {
   amount: Coin;
 }
-export interface Coin {
-  amount: string;
-  denom: string;
-}
 export interface DraftTvlResponse {
   denom: string;
   tvl: Uint128;
 }
+export interface DrawConfig {
+  accumulated_rewards: Uint128;
+  bonus_rewards: Uint128;
+  draw_state: DrawState;
+  max_tickets_per_user: Uint128;
+  min_tickets?: Uint128;
+  prize_tiers_bps: number[];
+  recurring: boolean;
+  rewards_fallback?: Addr | null;
+  round: number;
+  sales_close?: SalesClose | null;
+  sales_close_offset?: SalesClose | null;
+  sales_deadline?: number | null;
+  sales_duration?: number | null;
+  sold_out_at?: number | null;
+  ticket_denom: string;
+  ticket_precision?: number;
+  ticket_price: Uint128;
+  ticket_symbol: string;
+  total_tickets: Uint128;
+  undelegation_done_timestamp?: number | null;
+  validators: WeightedValidator[];
+  winners: Addr[];
+}
+export interface DrawProofResponse {
+  commitment?: HexBinary | null;
+  proof?: DrawProof | null;
+}
+export interface DrawProof {
+  block_height: number;
+  block_time_nanos: number;
+  commitment: HexBinary;
+  secret: HexBinary;
+  seed: HexBinary;
+  ticket_indexes: Uint128[];
+  total_tickets: Uint128;
+  winners: Addr[];
+}
+export interface DrawsResponse {
+  draws: DrawInfo[];
+}
+export interface DrawInfo {
+  config: DrawConfig;
+  draw_id: number;
+}
+export interface EarliestDrawTimeResponse {
+  draw_deadline?: number | null;
+  earliest_draw_time?: number | null;
+  sold_out_at?: number | null;
+}
+export interface FeesCollectedResponse {
+  fee_bps: number;
+  total_fees_collected: Uint128;
+  treasury?: string | null;
+}
 export interface TicketsSoldResponse {
   tickets_remaining: Uint128;
   tickets_sold: Uint128;
@@ -131,9 +482,64 @@ export interface ParticipantsResponse {
 }
 export interface ParticipantInfo {
   address: string;
+  ticket_subunits: Uint128;
   tickets: Uint128;
   win_chance: string;
 }
+export interface PauseStatusResponse {
+  guardian?: string | null;
+  paused: PauseFlags;
+}
+export interface PauseFlags {
+  admin_send: boolean;
+  burn: boolean;
+  buy: boolean;
+  payout: boolean;
+}
+export interface PendingWithdrawalsResponse {
+  withdrawal_threshold: Uint128;
+  withdrawal_timelock_seconds: number;
+  withdrawals: PendingWithdrawal[];
+}
+export interface PendingWithdrawal {
+  amount: Uint128;
+  executable_at: number;
+  id: number;
+  recipient: Addr;
+}
+export interface RoundHistoryResponse {
+  rounds: RoundSummary[];
+}
+export interface RoundSummary {
+  accumulated_rewards: Uint128;
+  bonus_rewards: Uint128;
+  finished_at: number;
+  proof?: DrawProof | null;
+  round: number;
+  ticket_price: Uint128;
+  tickets_burned: Uint128;
+  tickets_sold: Uint128;
+  total_claimed: Uint128;
+  total_tickets: Uint128;
+  winners: Addr[];
+}
+export type Decimal = string;
+export interface SlashingStatusResponse {
+  actual_principal: Uint128;
+  expected_principal: Uint128;
+  loss: Uint128;
+  loss_factor: Decimal;
+}
+export interface SolvencyResponse {
+  delegated: Uint128;
+  liquid: Uint128;
+  principal_owed: Uint128;
+  solvent: boolean;
+  total_assets: Uint128;
+  total_liabilities: Uint128;
+  unbonding: Uint128;
+  unpaid_prizes: Uint128;
+}
 export interface TicketHoldersResponse {
   holders: ParticipantInfo[];
   total_holders: number;
@@ -143,14 +549,29 @@ export interface TotalBurnedResponse {
 }
 export interface UserTicketsResponse {
   address: string;
+  ticket_subunits: Uint128;
   tickets: Uint128;
 }
 export interface UserWinChanceResponse {
   address: string;
+  ticket_subunits: Uint128;
   tickets: Uint128;
   win_chance: string;
 }
 export interface WinnerResponse {
+  fee: Uint128;
   rewards: Uint128;
-  winner?: string | null;
+  winners: WinnerInfo[];
+}
+export interface WinnerInfo {
+  address: string;
+  amount: Uint128;
+  paid: boolean;
+  share_bps: number;
+  tier: number;
+}
+export interface OwnershipForString {
+  owner?: string | null;
+  pending_expiry?: Expiration | null;
+  pending_owner?: string | null;
 }
\ No newline at end of file